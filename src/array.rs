@@ -0,0 +1,70 @@
+use core::{future::poll_fn, pin::pin, task::Poll};
+
+use crate::fuse::DebugFuse;
+
+/// Poll a fixed-size array of `Result` futures concurrently and resolve to their outputs in the same order.
+/// 
+/// It doesn't need any allocation so it is available without `alloc` feature.
+/// All futures, which are not completed yet, are polled on every wake up.
+/// 
+/// # Example
+/// ```
+/// use extra_result::collect_into_fut;
+/// 
+/// async fn read_sensor(id: u8) -> Result<u8, u8> {
+///     if id < 2 { Ok(id * 10) } else { Err(id) }
+/// }
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let results = collect_into_fut([read_sensor(0), read_sensor(1), read_sensor(2)]).await;
+/// assert_eq!(results, [Ok(0), Ok(10), Err(2)]);
+/// # }
+/// ```
+pub fn collect_into_fut<F, T, E, const N: usize>(futs: [F; N]) -> impl Future<Output = [Result<T, E>; N]>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    async move {
+        let mut futs = pin!(futs.map(DebugFuse::new));
+        let mut outputs: [Option<Result<T, E>>; N] = core::array::from_fn(|_| None);
+        let mut remaining = N;
+        poll_fn(move |cx| {
+            for (i, output) in outputs.iter_mut().enumerate() {
+                if output.is_some() {
+                    continue;
+                }
+                // SAFETY: The array is pinned and its elements are never moved out of it,
+                // so each element is structurally pinned.
+                let fut = unsafe { futs.as_mut().map_unchecked_mut(|futs| &mut futs[i]) };
+                if let Poll::Ready(v) = fut.poll(cx) {
+                    *output = Some(v);
+                    remaining -= 1;
+                }
+            }
+            if remaining == 0 {
+                Poll::Ready(core::array::from_fn(|i| outputs[i].take().expect("every future is completed")))
+            } else {
+                Poll::Pending
+            }
+        }).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::delayed;
+
+    #[tokio::test]
+    async fn collect_in_order() {
+        assert_eq!(
+            collect_into_fut([delayed(2, Ok(1)), delayed(1, Err(2)), delayed(0, Ok(3))]).await,
+            [Ok(1), Err(2), Ok(3)]
+        );
+    }
+    #[tokio::test]
+    async fn collect_empty() {
+        assert_eq!(collect_into_fut::<core::future::Ready<Result<u8, u8>>, u8, u8, 0>([]).await, []);
+    }
+}
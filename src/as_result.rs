@@ -0,0 +1,75 @@
+use crate::ExtraResult;
+
+/// A type that model success and failure and can be converted into a [Result].
+/// 
+/// It is a lightweight alternative to [Into] for a domain-specific outcome enum.
+/// Implementing [AsResult::as_result] is enough to reuse the async combinators of [ExtraResult] on it,
+/// starting with [AsResult::map_as_result_fut].
+/// 
+/// # Example
+/// ```
+/// use extra_result::*;
+/// 
+/// enum Outcome {
+///     Done(u8),
+///     Failed(&'static str),
+/// }
+/// 
+/// impl AsResult<u8, &'static str> for Outcome {
+///     fn as_result(self) -> Result<u8, &'static str> {
+///         match self {
+///             Outcome::Done(v) => Ok(v),
+///             Outcome::Failed(e) => Err(e),
+///         }
+///     }
+/// }
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// assert_eq!(Outcome::Done(1).map_as_result_fut(async |x| x + 1).await, Ok(2));
+/// assert_eq!(Outcome::Failed("rejected").map_as_result_fut(async |x| x + 1).await, Err("rejected"));
+/// # }
+/// ```
+pub trait AsResult<T, E> {
+    /// Convert `self` into a [Result].
+    #[allow(clippy::wrong_self_convention)]
+    fn as_result(self) -> Result<T, E>;
+    /// Convert `self` into a [Result] with [AsResult::as_result] then apply [ExtraResult::map_fut].
+    fn map_as_result_fut<U, F>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Self: Sized,
+        F: AsyncFnOnce(T) -> U,
+    {
+        self.as_result().map_fut(f)
+    }
+}
+
+impl<T, E> AsResult<T, E> for Result<T, E> {
+    #[inline]
+    fn as_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Flag(bool);
+
+    impl AsResult<(), ()> for Flag {
+        fn as_result(self) -> Result<(), ()> {
+            if self.0 { Ok(()) } else { Err(()) }
+        }
+    }
+
+    #[tokio::test]
+    async fn map_as_result_custom() {
+        assert_eq!(Flag(true).map_as_result_fut(async |_| 1u8).await, Ok(1u8));
+        assert_eq!(Flag(false).map_as_result_fut(async |_| 1u8).await, Err(()));
+    }
+    #[tokio::test]
+    async fn map_as_result_identity() {
+        assert_eq!(Ok::<u8, u8>(1).map_as_result_fut(async |x| x + 1).await, Ok(2u8));
+    }
+}
@@ -0,0 +1,132 @@
+use alloc::sync::Arc;
+use core::{
+    fmt,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
+};
+
+/// Poll statistics of a future wrapped by [instrument_fut].
+/// 
+/// It is shared with the [Instrumented] future so it can be read while or after the future is polled.
+/// The counters are relaxed atomics, it is meant for measurement, not for synchronization.
+#[derive(Clone, Debug, Default)]
+pub struct PollStats {
+    counters: Arc<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    polls: AtomicUsize,
+    pending: AtomicUsize,
+}
+
+impl PollStats {
+    /// Return how many times the wrapped future was polled.
+    pub fn polls(&self) -> usize {
+        self.counters.polls.load(Ordering::Relaxed)
+    }
+    /// Return how many times the wrapped future returned [Poll::Pending].
+    pub fn pending(&self) -> usize {
+        self.counters.pending.load(Ordering::Relaxed)
+    }
+    /// Return true if the wrapped future returned [Poll::Pending] at least once.
+    pub fn was_pending(&self) -> bool {
+        self.pending() > 0
+    }
+}
+
+/// A future that record [PollStats] of the wrapped future.
+/// 
+/// It is created by [instrument_fut] or [ExtraResult::instrumented_fut](crate::ExtraResult::instrumented_fut).
+/// 
+/// Its [Debug](fmt::Debug) output show the [PollStats] so far without requiring `Fut` to be [Debug](fmt::Debug).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Instrumented<Fut> {
+    fut: Fut,
+    stats: PollStats,
+}
+
+impl<Fut: Future> Future for Instrumented<Fut> {
+    type Output = Fut::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `fut` is structurally pinned. It is never moved out of `self`
+        // and `Instrumented` doesn't implement `Drop`. `stats` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        this.stats.counters.polls.fetch_add(1, Ordering::Relaxed);
+        let poll = unsafe { Pin::new_unchecked(&mut this.fut) }.poll(cx);
+        if poll.is_pending() {
+            this.stats.counters.pending.fetch_add(1, Ordering::Relaxed);
+        }
+        poll
+    }
+}
+
+impl<Fut> fmt::Debug for Instrumented<Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Instrumented")
+            .field("polls", &self.stats.polls())
+            .field("pending", &self.stats.pending())
+            .finish()
+    }
+}
+
+/// Wrap a future to count how many times it is polled and how many times it return [Poll::Pending].
+/// 
+/// It is useful to measure the overhead of the combinators in this crate compare to a hand-written `match`.
+/// This function is only available with `bench` feature.
+/// 
+/// # Example
+/// ```
+/// use extra_result::*;
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let (fut, stats) = instrument_fut(Result::<u8, u8>::Ok(1).map_fut(async |x| x + 1));
+/// assert_eq!(fut.await, Ok(2));
+/// assert_eq!(stats.polls(), 1);
+/// assert!(!stats.was_pending());
+/// # }
+/// ```
+pub fn instrument_fut<Fut: Future>(fut: Fut) -> (Instrumented<Fut>, PollStats) {
+    let stats = PollStats::default();
+    (Instrumented { fut, stats: stats.clone() }, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::{Sleeper, tests::YieldSleeper};
+
+    #[tokio::test]
+    async fn instrument_ready() {
+        let (fut, stats) = instrument_fut(core::future::ready(1u8));
+        assert_eq!(stats.polls(), 0);
+        assert_eq!(fut.await, 1);
+        assert_eq!(stats.polls(), 1);
+        assert_eq!(stats.pending(), 0);
+        assert!(!stats.was_pending());
+    }
+    #[tokio::test]
+    async fn instrument_pending() {
+        let (fut, stats) = instrument_fut(async {
+            YieldSleeper.sleep(0).await;
+            YieldSleeper.sleep(0).await;
+            1u8
+        });
+        assert_eq!(fut.await, 1);
+        assert_eq!(stats.polls(), 3);
+        assert_eq!(stats.pending(), 2);
+        assert!(stats.was_pending());
+    }
+    #[tokio::test]
+    async fn instrument_debug() {
+        let (fut, _) = instrument_fut(core::future::ready(1u8));
+        let mut fut = core::pin::pin!(fut);
+        assert_eq!(alloc::format!("{fut:?}"), "Instrumented { polls: 0, pending: 0 }");
+        assert_eq!(fut.as_mut().await, 1);
+        assert_eq!(alloc::format!("{fut:?}"), "Instrumented { polls: 1, pending: 0 }");
+    }
+}
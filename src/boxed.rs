@@ -0,0 +1,253 @@
+use alloc::boxed::Box;
+use core::pin::Pin;
+
+use crate::ExtraResult;
+
+/// A heap allocated, type erased [Future].
+/// 
+/// The future is not [Send] because the crate cannot name the future type returned by async functions
+/// to add the bound.
+pub type BoxedFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Boxed versions of the methods in [ExtraResult].
+/// 
+/// Each method behaves exactly like its [ExtraResult] counterpart without `_boxed` suffix
+/// but it return a [BoxedFuture] instead of an `impl Future`.
+/// It is useful when the futures need to be stored in a collection or returned from a trait object,
+/// for example when building a plugin registry.
+/// 
+/// Each call allocates the future on the heap and every poll goes through dynamic dispatch.
+/// Prefer the inline versions from [ExtraResult] unless a nameable type is needed.
+pub trait ExtraResultBoxed<T, E> {
+    /// Boxed version of [ExtraResult::map_fut].
+    fn map_fut_boxed<'a, U, F>(self, f: F) -> BoxedFuture<'a, Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a;
+    /// Boxed version of [ExtraResult::map_or_fut].
+    fn map_or_fut_boxed<'a, U, F>(self, default: U, f: F) -> BoxedFuture<'a, U>
+    where
+        F: AsyncFnOnce(T) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a;
+    /// Boxed version of [ExtraResult::map_or_else_fut].
+    fn map_or_else_fut_boxed<'a, U, D, F>(self, default: D, f: F) -> BoxedFuture<'a, U>
+    where
+        D: AsyncFnOnce(E) -> U + 'a,
+        F: AsyncFnOnce(T) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a;
+    /// Boxed version of [ExtraResult::map_err_fut].
+    fn map_err_fut_boxed<'a, F, U>(self, f: F) -> BoxedFuture<'a, Result<T, U>>
+    where
+        F: AsyncFnOnce(E) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a;
+    /// Boxed version of [ExtraResult::inspect_fut].
+    fn inspect_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, Result<T, E>>
+    where
+        F: AsyncFnOnce(&T) + 'a,
+        T: 'a,
+        E: 'a;
+    /// Boxed version of [ExtraResult::inspect_err_fut].
+    fn inspect_err_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, Result<T, E>>
+    where
+        F: AsyncFnOnce(&E) + 'a,
+        T: 'a,
+        E: 'a;
+    /// Boxed version of [ExtraResult::and_then_fut].
+    fn and_then_fut_boxed<'a, U, F>(self, f: F) -> BoxedFuture<'a, Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> Result<U, E> + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a;
+    /// Boxed version of [ExtraResult::or_else_fut].
+    fn or_else_fut_boxed<'a, U, F>(self, f: F) -> BoxedFuture<'a, Result<T, U>>
+    where
+        F: AsyncFnOnce(E) -> Result<T, U> + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a;
+    /// Boxed version of [ExtraResult::unwrap_or_else_fut].
+    fn unwrap_or_else_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, T>
+    where
+        F: AsyncFnOnce(E) -> T + 'a,
+        T: 'a,
+        E: 'a;
+    /// Boxed version of [ExtraResult::unwrap_or_fut].
+    fn unwrap_or_fut_boxed<'a, Fut>(self, default: Fut) -> BoxedFuture<'a, T>
+    where
+        Fut: Future<Output = T> + 'a,
+        T: 'a,
+        E: 'a;
+    /// Boxed version of [ExtraResult::is_ok_and_fut].
+    #[allow(clippy::wrong_self_convention)]
+    fn is_ok_and_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, bool>
+    where
+        F: AsyncFnOnce(&T) -> bool + 'a,
+        T: 'a,
+        E: 'a;
+    /// Boxed version of [ExtraResult::is_err_and_fut].
+    #[allow(clippy::wrong_self_convention)]
+    fn is_err_and_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, bool>
+    where
+        F: AsyncFnOnce(&E) -> bool + 'a,
+        T: 'a,
+        E: 'a;
+}
+
+impl<T, E> ExtraResultBoxed<T, E> for Result<T, E> {
+    #[inline]
+    fn map_fut_boxed<'a, U, F>(self, f: F) -> BoxedFuture<'a, Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a,
+    {
+        Box::pin(self.map_fut(f))
+    }
+    #[inline]
+    fn map_or_fut_boxed<'a, U, F>(self, default: U, f: F) -> BoxedFuture<'a, U>
+    where
+        F: AsyncFnOnce(T) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a,
+    {
+        Box::pin(self.map_or_fut(default, f))
+    }
+    #[inline]
+    fn map_or_else_fut_boxed<'a, U, D, F>(self, default: D, f: F) -> BoxedFuture<'a, U>
+    where
+        D: AsyncFnOnce(E) -> U + 'a,
+        F: AsyncFnOnce(T) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a,
+    {
+        Box::pin(self.map_or_else_fut(default, f))
+    }
+    #[inline]
+    fn map_err_fut_boxed<'a, F, U>(self, f: F) -> BoxedFuture<'a, Result<T, U>>
+    where
+        F: AsyncFnOnce(E) -> U + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a,
+    {
+        Box::pin(self.map_err_fut(f))
+    }
+    #[inline]
+    fn inspect_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, Result<T, E>>
+    where
+        F: AsyncFnOnce(&T) + 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::pin(self.inspect_fut(f))
+    }
+    #[inline]
+    fn inspect_err_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, Result<T, E>>
+    where
+        F: AsyncFnOnce(&E) + 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::pin(self.inspect_err_fut(f))
+    }
+    #[inline]
+    fn and_then_fut_boxed<'a, U, F>(self, f: F) -> BoxedFuture<'a, Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> Result<U, E> + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a,
+    {
+        Box::pin(self.and_then_fut(f))
+    }
+    #[inline]
+    fn or_else_fut_boxed<'a, U, F>(self, f: F) -> BoxedFuture<'a, Result<T, U>>
+    where
+        F: AsyncFnOnce(E) -> Result<T, U> + 'a,
+        T: 'a,
+        E: 'a,
+        U: 'a,
+    {
+        Box::pin(self.or_else_fut(f))
+    }
+    #[inline]
+    fn unwrap_or_else_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, T>
+    where
+        F: AsyncFnOnce(E) -> T + 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::pin(self.unwrap_or_else_fut(f))
+    }
+    #[inline]
+    fn unwrap_or_fut_boxed<'a, Fut>(self, default: Fut) -> BoxedFuture<'a, T>
+    where
+        Fut: Future<Output = T> + 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::pin(self.unwrap_or_fut(default))
+    }
+    #[inline]
+    fn is_ok_and_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, bool>
+    where
+        F: AsyncFnOnce(&T) -> bool + 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::pin(self.is_ok_and_fut(f))
+    }
+    #[inline]
+    fn is_err_and_fut_boxed<'a, F>(self, f: F) -> BoxedFuture<'a, bool>
+    where
+        F: AsyncFnOnce(&E) -> bool + 'a,
+        T: 'a,
+        E: 'a,
+    {
+        Box::pin(self.is_err_and_fut(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[tokio::test]
+    async fn map_boxed_on_ok() {
+        Result::<u8, ()>::Ok(1)
+            .map_fut_boxed(async |x| { x + 1 }).await
+            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn map_boxed_on_err() {
+        Result::<u8, u8>::Err(1)
+            .map_fut_boxed(async |x| { x + 1 }).await
+            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    }
+    #[tokio::test]
+    async fn heterogeneous_collection() {
+        let futs: Vec<BoxedFuture<'_, Result<u8, u8>>> = Vec::from([
+            Result::<u8, u8>::Ok(1).map_fut_boxed(async |x| { x + 1 }),
+            Result::<u8, u8>::Ok(1).and_then_fut_boxed(async |x| { Err(x + 2) }),
+            Result::<u8, u8>::Err(1).map_err_fut_boxed(async |x| { x + 3 }),
+        ]);
+        let mut results = Vec::new();
+        for fut in futs {
+            results.push(fut.await);
+        }
+        assert_eq!(results, [Ok(2), Err(3), Err(4)]);
+    }
+}
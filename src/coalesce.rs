@@ -0,0 +1,115 @@
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{cell::RefCell, fmt};
+
+use crate::{BoxedFuture, SharedResult};
+
+/// The computation of a key, shared by every caller of that key.
+type Computation<'a, T, E> = SharedResult<BoxedFuture<'a, Result<T, E>>>;
+
+/// A map of in-flight computations of [Result] that coalesce concurrent requests of the same key, also known as single-flight.
+/// 
+/// [CoalesceMap::get_or_compute_fut] runs at most one computation per key at a time.
+/// Every caller that ask for the same key while it is running awaits the same computation
+/// instead of starting a new one, e.g. to prevent a stampede of cache misses.
+/// Once the computation completed, the key is removed so the next call start a fresh computation.
+/// It doesn't cache the outcome.
+/// 
+/// Every caller get a clone of the outcome so both `T` and `E` must be [Clone].
+/// The key must be [Ord] and [Clone] because the computations are kept in a [BTreeMap].
+/// The computations are boxed and they may borrow anything that outlive `'a`.
+/// 
+/// It is single-threaded. It is built on [SharedResult] so it is neither [Send] nor [Sync]
+/// and all the callers must be on the same thread. It doesn't need any runtime nor `std`.
+/// A computation must not ask for its own key, otherwise it panics.
+/// If every caller of a key is dropped before the computation completed, the computation is kept
+/// and the next caller of that key resume it.
+/// This type is only available with `alloc` feature.
+pub struct CoalesceMap<'a, K, T, E> {
+    in_flight: RefCell<BTreeMap<K, Computation<'a, T, E>>>,
+}
+
+impl<'a, K, T, E> CoalesceMap<'a, K, T, E>
+where
+    K: Ord + Clone,
+{
+    /// Create an empty [CoalesceMap].
+    pub fn new() -> Self {
+        CoalesceMap { in_flight: RefCell::new(BTreeMap::new()) }
+    }
+    /// Return the number of keys that have a computation in progress.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.borrow().len()
+    }
+    /// Await the computation of the `key`, starting it with `compute` if none is in progress.
+    /// 
+    /// If a computation of the same key is in progress, `compute` is dropped without being called
+    /// and it resolves to a clone of the outcome of that computation.
+    /// Otherwise, it calls `compute` and other callers of the same key join it until it completed.
+    pub fn get_or_compute_fut<F>(&self, key: K, compute: F) -> impl Future<Output = Result<T, E>>
+    where
+        T: Clone + 'a,
+        E: Clone + 'a,
+        F: AsyncFnOnce() -> Result<T, E> + 'a,
+    {
+        async move {
+            let mut shared = self.in_flight.borrow_mut().entry(key.clone()).or_insert_with(|| {
+                let fut: BoxedFuture<'a, Result<T, E>> = Box::pin(async move { compute().await });
+                SharedResult::new(fut)
+            }).clone();
+            let result = (&mut shared).await;
+            let mut in_flight = self.in_flight.borrow_mut();
+            // A new computation of the same key may already be in progress if this caller was woken up late.
+            if in_flight.get(&key).is_some_and(|other| other.ptr_eq(&shared)) {
+                in_flight.remove(&key);
+            }
+            result
+        }
+    }
+}
+
+impl<K, T, E> Default for CoalesceMap<'_, K, T, E>
+where
+    K: Ord + Clone,
+{
+    fn default() -> Self {
+        CoalesceMap::new()
+    }
+}
+
+impl<K, T, E> fmt::Debug for CoalesceMap<'_, K, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let in_flight = self.in_flight.try_borrow().map(|m| m.len());
+        f.debug_struct("CoalesceMap").field("in_flight", &format_args!("{}", in_flight.unwrap_or_default())).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use crate::time::{Sleeper, tests::YieldSleeper};
+
+    #[tokio::test]
+    async fn coalesce_same_key() {
+        let runs = Cell::new(0);
+        let compute = async |x: u8| {
+            runs.set(runs.get() + 1);
+            YieldSleeper.sleep(0).await;
+            Ok::<u8, u8>(x)
+        };
+        let map = CoalesceMap::new();
+        let a = map.get_or_compute_fut(1u8, async || compute(1).await);
+        let b = map.get_or_compute_fut(1u8, async || compute(2).await);
+        let c = map.get_or_compute_fut(2u8, async || compute(3).await);
+        assert_eq!(tokio::join!(a, b, c), (Ok(1u8), Ok(1u8), Ok(3u8)));
+        assert_eq!(runs.get(), 2);
+        assert_eq!(map.in_flight(), 0);
+    }
+    #[tokio::test]
+    async fn coalesce_compute_again_after_completion() {
+        let map = CoalesceMap::new();
+        assert_eq!(map.get_or_compute_fut(1u8, async || Err::<u8, u8>(1)).await, Err(1u8));
+        assert_eq!(map.get_or_compute_fut(1u8, async || Ok::<u8, u8>(2)).await, Ok(2u8));
+        assert_eq!(alloc::format!("{map:?}"), "CoalesceMap { in_flight: 0 }");
+    }
+}
@@ -0,0 +1,29 @@
+/// A value that is either of type `L` or of type `R`.
+/// 
+/// It is used by [select_fut](crate::select_fut) to tell which future completed first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// The left value, e.g. the first future completed first.
+    Left(L),
+    /// The right value, e.g. the second future completed first.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Return the left value if it is [Either::Left], otherwise returns None.
+    #[inline]
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(l) => Some(l),
+            Either::Right(_) => None,
+        }
+    }
+    /// Return the right value if it is [Either::Right], otherwise returns None.
+    #[inline]
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use core::fmt;
+
+/// An error returned by cancelable combinators such as [ExtraResult::map_fut_cancelable](crate::ExtraResult::map_fut_cancelable).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Cancelled<E> {
+    /// The cancellation token completed before the operation.
+    Cancelled,
+    /// The original error of the [Result].
+    Inner(E),
+}
+
+impl<E> fmt::Display for Cancelled<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cancelled::Cancelled => f.write_str("operation cancelled"),
+            Cancelled::Inner(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E> core::error::Error for Cancelled<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Cancelled::Cancelled => None,
+            Cancelled::Inner(e) => Some(e),
+        }
+    }
+}
+
+/// An error returned by combinators with a deadline such as [ExtraResult::map_fut_timeout](crate::ExtraResult::map_fut_timeout).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeoutOr<E> {
+    /// The timer completed before the operation.
+    Elapsed,
+    /// The original error of the [Result].
+    Inner(E),
+}
+
+impl<E> fmt::Display for TimeoutOr<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeoutOr::Elapsed => f.write_str("deadline elapsed"),
+            TimeoutOr::Inner(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<E> core::error::Error for TimeoutOr<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            TimeoutOr::Elapsed => None,
+            TimeoutOr::Inner(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use core::ops::ControlFlow;
+
+/// Run an async function that can fail.
+/// 
+/// It calls the async function and return its [Result] as is.
+/// It is a named entry point for async fallible code so a pipeline can start with it
+/// and continue with methods of [ExtraResult](crate::ExtraResult).
+pub fn try_fut<T, E, F>(f: F) -> impl Future<Output = Result<T, E>>
+where
+    F: AsyncFnOnce() -> Result<T, E>,
+{
+    async {
+        f().await
+    }
+}
+
+/// Run an async function that can fail and fall back to the default value on error.
+/// 
+/// It calls the async function and return the value if it is Ok.
+/// If it is Err, the error is dropped and `T::default()` is returned.
+pub fn try_fut_default<T, E, F>(f: F) -> impl Future<Output = T>
+where
+    T: Default,
+    F: AsyncFnOnce() -> Result<T, E>,
+{
+    async {
+        f().await.unwrap_or_default()
+    }
+}
+
+/// Lift a value that can be converted into a [Result] into a [Future] of [Result].
+/// 
+/// It converts the value with [Into] and return a future that is ready immediately.
+/// It is an uniform entry point of a pipeline for types that model fallibility differently.
+/// 
+/// # Example
+/// ```
+/// use extra_result::*;
+/// 
+/// enum Outcome {
+///     Done(u8),
+///     Failed(&'static str),
+/// }
+/// 
+/// impl From<Outcome> for Result<u8, &'static str> {
+///     fn from(outcome: Outcome) -> Self {
+///         match outcome {
+///             Outcome::Done(v) => Ok(v),
+///             Outcome::Failed(e) => Err(e),
+///         }
+///     }
+/// }
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// assert_eq!(lift_fut(Outcome::Done(1)).await.map_fut(async |x| x + 1).await, Ok(2));
+/// assert_eq!(lift_fut::<_, u8, _>(Outcome::Failed("denied")).await, Err("denied"));
+/// # }
+/// ```
+pub fn lift_fut<V, T, E>(value: V) -> impl Future<Output = Result<T, E>>
+where
+    V: Into<Result<T, E>>,
+{
+    core::future::ready(value.into())
+}
+
+/// Convert a [ControlFlow] into a [Future] of [Result].
+/// 
+/// It maps `Continue(t)` to `Ok(t)` and `Break(e)` to `Err(e)` and return a future that is ready immediately.
+/// It is the inverse of [ExtraResult::into_control_flow_fut](crate::ExtraResult::into_control_flow_fut).
+pub fn from_control_flow_fut<T, E>(flow: ControlFlow<E, T>) -> impl Future<Output = Result<T, E>> {
+    core::future::ready(match flow {
+        ControlFlow::Continue(v) => Ok(v),
+        ControlFlow::Break(e) => Err(e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtraResult;
+
+    #[tokio::test]
+    async fn try_on_ok() {
+        assert_eq!(try_fut(async || Ok::<u8, u8>(1)).await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn try_on_err() {
+        assert_eq!(try_fut(async || Err::<u8, u8>(1)).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn try_in_pipeline() {
+        assert_eq!(
+            try_fut(async || Ok::<u8, u8>(1)).await
+                .map_fut(async |x| x + 1).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn try_default_on_ok() {
+        assert_eq!(try_fut_default(async || Ok::<u8, u8>(1)).await, 1u8);
+    }
+    #[tokio::test]
+    async fn try_default_on_err() {
+        assert_eq!(try_fut_default(async || Err::<u8, u8>(1)).await, 0u8);
+    }
+    #[tokio::test]
+    async fn lift_result() {
+        assert_eq!(lift_fut(Ok::<u8, u8>(1)).await, Ok(1u8));
+        assert_eq!(lift_fut(Err::<u8, u8>(1)).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn from_control_flow() {
+        assert_eq!(from_control_flow_fut(ControlFlow::<u8, u8>::Continue(1)).await, Ok(1u8));
+        assert_eq!(from_control_flow_fut(ControlFlow::<u8, u8>::Break(1)).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn control_flow_round_trip() {
+        assert_eq!(from_control_flow_fut(Ok::<u8, u8>(1).into_control_flow_fut().await).await, Ok(1u8));
+        assert_eq!(from_control_flow_fut(Err::<u8, u8>(1).into_control_flow_fut().await).await, Err(1u8));
+    }
+}
@@ -0,0 +1,63 @@
+use core::{pin::Pin, task::{Context, Poll}};
+
+/// A future that panics in debug builds if it is polled again after it completed.
+/// 
+/// It wraps the futures that a combinator keeps around after they completed, e.g. the finished arm of [crate::both_ok_fut],
+/// to check that the combinator never polls them again.
+/// In release builds, it doesn't record anything and simply forward the poll to the wrapped future.
+pub(crate) struct DebugFuse<Fut> {
+    fut: Fut,
+    #[cfg(debug_assertions)]
+    done: bool,
+}
+
+impl<Fut> DebugFuse<Fut> {
+    /// Wrap a future into a [DebugFuse].
+    #[inline]
+    pub(crate) fn new(fut: Fut) -> Self {
+        DebugFuse {
+            fut,
+            #[cfg(debug_assertions)]
+            done: false,
+        }
+    }
+}
+
+impl<Fut: Future> Future for DebugFuse<Fut> {
+    type Output = Fut::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `fut` is structurally pinned. It is never moved out of `self`
+        // and `DebugFuse` doesn't implement `Drop`. `done` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        #[cfg(debug_assertions)]
+        debug_assert!(!this.done, "a completed future is polled again");
+        let poll = unsafe { Pin::new_unchecked(&mut this.fut) }.poll(cx);
+        #[cfg(debug_assertions)]
+        if poll.is_ready() {
+            this.done = true;
+        }
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn debug_fuse_forward() {
+        assert_eq!(DebugFuse::new(async { 1u8 }).await, 1);
+    }
+    #[cfg(debug_assertions)]
+    #[tokio::test]
+    #[should_panic(expected = "a completed future is polled again")]
+    async fn debug_fuse_polled_again() {
+        use core::{future::poll_fn, pin::pin};
+
+        let mut fut = pin!(DebugFuse::new(core::future::ready(1u8)));
+        poll_fn(|cx| fut.as_mut().poll(cx)).await;
+        poll_fn(|cx| fut.as_mut().poll(cx)).await;
+    }
+}
@@ -0,0 +1,112 @@
+use core::{
+    fmt::Debug,
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// The global error hook. It is null when no hook is set.
+static ERROR_HOOK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Set a global hook that is called with every error observed by [ExtraResult::report_err_fut](crate::ExtraResult::report_err_fut),
+/// [ExtraResult::map_err_fut](crate::ExtraResult::map_err_fut) or [ExtraResult::inspect_err_fut](crate::ExtraResult::inspect_err_fut).
+/// 
+/// It gives a central place to log all errors flowing through the combinators without instrumenting every call site.
+/// `map_err_fut` and `inspect_err_fut` don't require the error to be [Debug] so the hook get the name of the combinator and
+/// the type of the error instead, e.g. `ObservedErr { combinator: "map_err_fut", error: "u8" }`.
+/// Setting a new hook replace the previous one. It is safe to call from any thread.
+/// This function is only available with `std` feature.
+pub fn set_error_hook(f: fn(&dyn Debug)) {
+    ERROR_HOOK.store(f as *mut (), Ordering::Release);
+}
+
+/// Remove the global error hook set by [set_error_hook].
+/// 
+/// Errors observed afterward are not reported anywhere.
+pub fn clear_error_hook() {
+    ERROR_HOOK.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Call the global error hook with the error if it is set. Otherwise, it is a no-op.
+pub(crate) fn report_err(e: &dyn Debug) {
+    let hook = ERROR_HOOK.load(Ordering::Acquire);
+    if !hook.is_null() {
+        // SAFETY: The only non-null value ever stored is a `fn(&dyn Debug)` in `set_error_hook`.
+        let hook = unsafe { core::mem::transmute::<*mut (), fn(&dyn Debug)>(hook) };
+        hook(e);
+    }
+}
+
+/// An error observed by a combinator that doesn't require the error to be [Debug].
+struct ObservedErr {
+    combinator: &'static str,
+    error: &'static str,
+}
+
+impl Debug for ObservedErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ObservedErr").field("combinator", &self.combinator).field("error", &self.error).finish()
+    }
+}
+
+/// Call the global error hook with the name of the combinator and the type of the error if it is set.
+pub(crate) fn report_observed<E>(combinator: &'static str) {
+    report_err(&ObservedErr { combinator, error: core::any::type_name::<E>() });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cancelled, ExtraResult, TimeoutOr};
+    use core::{fmt, sync::atomic::AtomicUsize};
+
+    static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+    /// An error type only used here so the errors reported by other tests running in parallel are not counted.
+    #[derive(Debug, PartialEq)]
+    struct HookProbe;
+
+    impl fmt::Display for HookProbe {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("probe")
+        }
+    }
+
+    impl core::error::Error for HookProbe {}
+
+    fn count_hook(e: &dyn Debug) {
+        if std::format!("{e:?}").contains("HookProbe") {
+            REPORTED.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // The hook is global so every case is in a single test to avoid interference between parallel tests.
+    #[tokio::test]
+    async fn hook_fires_once_per_error() {
+        assert_eq!(Result::<u8, _>::Err(HookProbe).report_err_fut().await, Err(HookProbe));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 0);
+        set_error_hook(count_hook);
+        assert_eq!(Result::<u8, HookProbe>::Ok(1).report_err_fut().await, Ok(1u8));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 0);
+        assert_eq!(Result::<u8, _>::Err(HookProbe).report_err_fut().await, Err(HookProbe));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 1);
+        assert_eq!(Result::<u8, _>::Err(HookProbe).report_err_fut().await, Err(HookProbe));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 2);
+        // Every error of the source chain is reported.
+        assert_eq!(
+            Result::<u8, _>::Err(Cancelled::Inner(TimeoutOr::Inner(HookProbe))).source_chain_fut().await, 
+            Err(Cancelled::Inner(TimeoutOr::Inner(HookProbe)))
+        );
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 5);
+        // The combinators that observe an error report it even if it is not `Debug`.
+        assert_eq!(Result::<u8, HookProbe>::Ok(1).map_err_fut(async |_| 0u8).await, Ok(1u8));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 5);
+        assert_eq!(Result::<u8, _>::Err(HookProbe).map_err_fut(async |_| 0u8).await, Err(0u8));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 6);
+        assert_eq!(Result::<u8, _>::Err(HookProbe).inspect_err_fut(async |_| {}).await, Err(HookProbe));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 7);
+        clear_error_hook();
+        assert_eq!(Result::<u8, _>::Err(HookProbe).report_err_fut().await, Err(HookProbe));
+        assert_eq!(Result::<u8, _>::Err(HookProbe).inspect_err_fut(async |_| {}).await, Err(HookProbe));
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 7);
+    }
+}
@@ -0,0 +1,263 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::join::join_all;
+
+/// Add extra functionalities to an [Iterator] that is related to [Result].
+/// 
+/// Each method puts its own requirement on the item of the [Iterator].
+pub trait ExtraResultIter: Iterator {
+    /// Await all the futures of [Result] concurrently and split the Ok values from the Err values.
+    /// 
+    /// It returns a tuple of all Ok values and all Err values.
+    /// The values in each of them are in the same order as the iterator.
+    /// This method is only available with `alloc` feature.
+    #[cfg(feature = "alloc")]
+    fn partition_results_fut<T, E>(self) -> impl Future<Output = (Vec<T>, Vec<E>)>
+    where
+        Self: Sized,
+        Self::Item: Future<Output = Result<T, E>>;
+    /// Run an async side effect that can fail on every item, one at a time.
+    /// 
+    /// It calls the async function with each item in the order of the iterator.
+    /// The next item is pulled only after the function of previous item returned Ok.
+    /// If the function returns Err, it stops and return the error. The remaining items are left untouched.
+    /// If all of them returned Ok, it returns `Ok(())`.
+    fn try_for_each_fut<E, F>(self, f: F) -> impl Future<Output = Result<(), E>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> Result<(), E>;
+    /// Reduce the [Result] items pairwise with async function that can fail.
+    /// 
+    /// It calls the async function with the accumulated value and the value of next item, in the order of the iterator.
+    /// If an item is Err or the function returns Err, it stops and return the error. The remaining items are left untouched.
+    /// It returns `None` if the iterator is empty. Otherwise, it returns `Some` of the final result.
+    /// A single Ok item is returned as is without calling the function.
+    fn try_reduce_fut<T, E, F>(self, f: F) -> impl Future<Output = Option<Result<T, E>>>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(T, T) -> Result<T, E>;
+    /// Check if every item is Ok and satisfy the async predicate.
+    /// 
+    /// It calls the async function with the reference to the value of each item, in the order of the iterator.
+    /// It stops and return false at the first Err item or the first value that doesn't satisfy the predicate.
+    /// The remaining items are left untouched. An empty iterator returns true.
+    fn all_ok_and_fut<T, E, F>(self, f: F) -> impl Future<Output = bool>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(&T) -> bool;
+    /// Check if any item is Err and satisfy the async predicate.
+    /// 
+    /// It calls the async function with the reference to the error of each Err item, in the order of the iterator.
+    /// Ok items are skipped. It stops and return true at the first error that satisfy the predicate.
+    /// The remaining items are left untouched. An empty iterator returns false.
+    fn any_err_fut<T, E, F>(self, f: F) -> impl Future<Output = bool>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(&E) -> bool;
+}
+
+impl<I> ExtraResultIter for I
+where
+    I: Iterator,
+{
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn partition_results_fut<T, E>(self) -> impl Future<Output = (Vec<T>, Vec<E>)>
+    where
+        Self: Sized,
+        Self::Item: Future<Output = Result<T, E>>,
+    {
+        async {
+            let mut oks = Vec::new();
+            let mut errs = Vec::new();
+            for result in join_all(self).await {
+                match result {
+                    Ok(v) => oks.push(v),
+                    Err(e) => errs.push(e),
+                }
+            }
+            (oks, errs)
+        }
+    }
+    #[inline]
+    fn try_for_each_fut<E, F>(self, mut f: F) -> impl Future<Output = Result<(), E>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> Result<(), E>,
+    {
+        async move {
+            for item in self {
+                f(item).await?;
+            }
+            Ok(())
+        }
+    }
+    #[inline]
+    fn try_reduce_fut<T, E, F>(mut self, mut f: F) -> impl Future<Output = Option<Result<T, E>>>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(T, T) -> Result<T, E>,
+    {
+        async move {
+            let mut acc = match self.next()? {
+                Ok(v) => v,
+                Err(e) => return Some(Err(e)),
+            };
+            for item in self {
+                let v = match item {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                acc = match f(acc, v).await {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+            }
+            Some(Ok(acc))
+        }
+    }
+    #[inline]
+    fn all_ok_and_fut<T, E, F>(self, mut f: F) -> impl Future<Output = bool>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(&T) -> bool,
+    {
+        async move {
+            for item in self {
+                match item {
+                    Ok(v) if f(&v).await => continue,
+                    _ => return false,
+                }
+            }
+            true
+        }
+    }
+    #[inline]
+    fn any_err_fut<T, E, F>(self, mut f: F) -> impl Future<Output = bool>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: AsyncFnMut(&E) -> bool,
+    {
+        async move {
+            for item in self {
+                if let Err(e) = item && f(&e).await {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn partition_results_mixed() {
+        let futs = (0..5u8).map(|i| async move {
+            if i % 2 == 0 {
+                tokio::task::yield_now().await;
+                Ok(i)
+            } else {
+                Err(i)
+            }
+        });
+        assert_eq!(
+            futs.partition_results_fut().await, 
+            (Vec::from([0u8, 2, 4]), Vec::from([1u8, 3]))
+        );
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn partition_results_empty() {
+        assert_eq!(
+            core::iter::empty::<core::future::Ready<Result<u8, u8>>>().partition_results_fut().await, 
+            (Vec::new(), Vec::new())
+        );
+    }
+    #[tokio::test]
+    async fn try_for_each_all_ok() {
+        let mut sum = 0u8;
+        assert_eq!(
+            (1..=3u8).try_for_each_fut(async |x| -> Result<(), u8> { sum += x; Ok(()) }).await, 
+            Ok(())
+        );
+        assert_eq!(sum, 6);
+    }
+    #[tokio::test]
+    async fn try_for_each_stop_at_err() {
+        let mut iter = 1..=5u8;
+        let mut seen = 0u8;
+        assert_eq!(
+            iter.by_ref().try_for_each_fut(async |x| { seen = x; if x == 2 { Err(x) } else { Ok(()) } }).await, 
+            Err(2u8)
+        );
+        assert_eq!(seen, 2);
+        assert_eq!(iter.next(), Some(3u8));
+    }
+    #[tokio::test]
+    async fn try_reduce_all_ok() {
+        assert_eq!(
+            [Ok(1u8), Ok(2), Ok(3)].into_iter().try_reduce_fut(async |a, b| -> Result<u8, u8> { Ok(a + b) }).await, 
+            Some(Ok(6u8))
+        );
+    }
+    #[tokio::test]
+    async fn try_reduce_single() {
+        assert_eq!(
+            [Ok::<u8, u8>(1)].into_iter().try_reduce_fut(async |_, _| panic!("This should never be called")).await, 
+            Some(Ok(1u8))
+        );
+    }
+    #[tokio::test]
+    async fn try_reduce_empty() {
+        assert_eq!(
+            core::iter::empty::<Result<u8, u8>>().try_reduce_fut(async |a, b| Ok(a + b)).await, 
+            None
+        );
+    }
+    #[tokio::test]
+    async fn try_reduce_err_item() {
+        let mut iter = [Ok(1u8), Err(2u8), Ok(3)].into_iter();
+        assert_eq!(iter.by_ref().try_reduce_fut(async |a, b| Ok(a + b)).await, Some(Err(2u8)));
+        assert_eq!(iter.next(), Some(Ok(3u8)));
+    }
+    #[tokio::test]
+    async fn try_reduce_err_from_function() {
+        let mut iter = [Ok(1u8), Ok(2), Ok(3)].into_iter();
+        assert_eq!(iter.by_ref().try_reduce_fut(async |a, b| Err(a + b)).await, Some(Err(3u8)));
+        assert_eq!(iter.next(), Some(Ok(3u8)));
+    }
+    #[tokio::test]
+    async fn all_ok_and_true() {
+        assert!([Ok::<u8, u8>(1), Ok(2)].into_iter().all_ok_and_fut(async |x| *x > 0).await);
+        assert!(core::iter::empty::<Result<u8, u8>>().all_ok_and_fut(async |_| false).await);
+    }
+    #[tokio::test]
+    async fn all_ok_and_stop_at_err() {
+        let mut iter = [Ok(1u8), Err(2u8), Ok(3)].into_iter();
+        assert!(!iter.by_ref().all_ok_and_fut(async |x| *x > 0).await);
+        assert_eq!(iter.next(), Some(Ok(3u8)));
+    }
+    #[tokio::test]
+    async fn all_ok_and_stop_at_predicate() {
+        let mut iter = [Ok::<u8, u8>(1), Ok(2), Ok(3)].into_iter();
+        assert!(!iter.by_ref().all_ok_and_fut(async |x| *x < 2).await);
+        assert_eq!(iter.next(), Some(Ok(3u8)));
+    }
+    #[tokio::test]
+    async fn any_err_true() {
+        let mut iter = [Ok(1u8), Err(2u8), Err(3), Err(4)].into_iter();
+        assert!(iter.by_ref().any_err_fut(async |e| *e > 2).await);
+        assert_eq!(iter.next(), Some(Err(4u8)));
+    }
+    #[tokio::test]
+    async fn any_err_false() {
+        assert!(![Ok(1u8), Err(2u8)].into_iter().any_err_fut(async |e| *e > 2).await);
+        assert!(!core::iter::empty::<Result<u8, u8>>().any_err_fut(async |_| true).await);
+    }
+}
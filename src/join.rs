@@ -0,0 +1,182 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{future::poll_fn, pin::Pin, task::Poll};
+
+use crate::fuse::DebugFuse;
+
+/// Poll all the given futures concurrently and resolve to their outputs in the same order as the iterator.
+/// 
+/// All futures, which are not completed yet, are polled on every wake up.
+pub(crate) fn join_all<I>(futs: I) -> impl Future<Output = Vec<<I::Item as Future>::Output>>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    async move {
+        let mut futs: Vec<Pin<Box<DebugFuse<I::Item>>>> = futs.into_iter().map(|f| Box::pin(DebugFuse::new(f))).collect();
+        let mut outputs: Vec<Option<<I::Item as Future>::Output>> = futs.iter().map(|_| None).collect();
+        let mut remaining = futs.len();
+        poll_fn(move |cx| {
+            for (fut, output) in futs.iter_mut().zip(outputs.iter_mut()) {
+                if output.is_some() {
+                    continue;
+                }
+                if let Poll::Ready(v) = fut.as_mut().poll(cx) {
+                    *output = Some(v);
+                    remaining -= 1;
+                }
+            }
+            if remaining == 0 {
+                Poll::Ready(outputs.drain(..).flatten().collect())
+            } else {
+                Poll::Pending
+            }
+        }).await
+    }
+}
+
+/// Poll all the given [Result] futures concurrently and resolve to their outputs in the order of the iterator.
+/// 
+/// The output at each index is the output of the future at the same index, regardless of which one completed first.
+/// It is useful when the output must be reproducible, e.g. a batch report.
+/// Every future is polled to completion, an error doesn't stop the others.
+/// 
+/// The futures are boxed so they can be `!Unpin`.
+/// This function is only available with `alloc` feature.
+pub fn join_all_fut<T, E, I>(futs: I) -> impl Future<Output = Vec<Result<T, E>>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>>,
+{
+    join_all(futs)
+}
+
+/// Same as [join_all_fut] but resolve to the outputs in the order that the futures completed.
+/// 
+/// All futures, which are not completed yet, are polled on every wake up in the order of the iterator.
+/// If many futures complete in the same round of polling, their outputs are in the order of the iterator.
+/// It is useful when the outputs are aggregated without caring which future they came from.
+/// 
+/// The futures are boxed so they can be `!Unpin`.
+/// This function is only available with `alloc` feature.
+pub fn join_all_unordered_fut<T, E, I>(futs: I) -> impl Future<Output = Vec<Result<T, E>>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>>,
+{
+    async move {
+        let mut futs: Vec<Option<Pin<Box<I::Item>>>> = futs.into_iter().map(|f| Some(Box::pin(f))).collect();
+        let mut outputs = Vec::with_capacity(futs.len());
+        poll_fn(move |cx| {
+            for slot in futs.iter_mut() {
+                let Some(fut) = slot.as_mut() else { continue };
+                if let Poll::Ready(result) = fut.as_mut().poll(cx) {
+                    *slot = None;
+                    outputs.push(result);
+                }
+            }
+            if outputs.len() == futs.len() {
+                Poll::Ready(core::mem::take(&mut outputs))
+            } else {
+                Poll::Pending
+            }
+        }).await
+    }
+}
+
+/// Poll all the given fallible side effects concurrently and fail fast on the first error.
+/// 
+/// All futures, which are not completed yet, are polled on every wake up in the order of the iterator.
+/// `on_err` is called with every error as soon as it is observed, e.g. to log it.
+/// After a round of polling that observed any error, the remaining futures are dropped and
+/// the first error observed in that round, in the order of the iterator, is returned.
+/// Errors observed later in the same round are passed to `on_err` and then dropped.
+/// If all of them resolve to Ok, it returns `Ok(())`. An empty iterator resolves to `Ok(())` immediately.
+/// 
+/// The futures are boxed so they can be `!Unpin`.
+/// This function is only available with `alloc` feature.
+pub fn try_join_with_fut<E, I, F>(futs: I, mut on_err: F) -> impl Future<Output = Result<(), E>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<(), E>>,
+    F: FnMut(&E),
+{
+    async move {
+        let mut futs: Vec<Option<Pin<Box<I::Item>>>> = futs.into_iter().map(|f| Some(Box::pin(f))).collect();
+        let mut remaining = futs.len();
+        poll_fn(move |cx| {
+            let mut first_err = None;
+            for slot in futs.iter_mut() {
+                let Some(fut) = slot.as_mut() else { continue };
+                if let Poll::Ready(result) = fut.as_mut().poll(cx) {
+                    *slot = None;
+                    remaining -= 1;
+                    if let Err(e) = result {
+                        on_err(&e);
+                        first_err.get_or_insert(e);
+                    }
+                }
+            }
+            if let Some(e) = first_err {
+                futs.clear();
+                Poll::Ready(Err(e))
+            } else if remaining == 0 {
+                Poll::Ready(Ok(()))
+            } else {
+                Poll::Pending
+            }
+        }).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::delayed;
+
+    #[tokio::test]
+    async fn join_all_input_order() {
+        assert_eq!(
+            join_all_fut([delayed(2, Err(1)), delayed(0, Err(2)), delayed(1, Ok(()))]).await, 
+            [Err(1u8), Err(2), Ok(())]
+        );
+    }
+    #[tokio::test]
+    async fn join_all_completion_order() {
+        assert_eq!(
+            join_all_unordered_fut([delayed(2, Err(1)), delayed(0, Err(2)), delayed(1, Ok(())), delayed(0, Err(4))]).await, 
+            [Err(2u8), Err(4), Ok(()), Err(1)]
+        );
+    }
+    #[tokio::test]
+    async fn join_all_unordered_empty() {
+        assert!(join_all_unordered_fut(core::iter::empty::<core::future::Ready<Result<(), u8>>>()).await.is_empty());
+    }
+    #[tokio::test]
+    async fn try_join_all_ok() {
+        let mut observed = Vec::new();
+        assert_eq!(
+            try_join_with_fut([delayed(1, Ok::<(), u8>(())), delayed(0, Ok(()))], |e| observed.push(*e)).await, 
+            Ok(())
+        );
+        assert!(observed.is_empty());
+    }
+    #[tokio::test]
+    async fn try_join_fail_fast() {
+        let mut observed = Vec::new();
+        assert_eq!(
+            try_join_with_fut(
+                [delayed(3, Err(1)), delayed(1, Err(2)), delayed(1, Err(3)), delayed(0, Ok(()))],
+                |e| observed.push(*e),
+            ).await, 
+            Err(2u8)
+        );
+        assert_eq!(observed, [2u8, 3]);
+    }
+    #[tokio::test]
+    async fn try_join_empty() {
+        assert_eq!(
+            try_join_with_fut(core::iter::empty::<core::future::Ready<Result<(), u8>>>(), |_| {}).await, 
+            Ok(())
+        );
+    }
+}
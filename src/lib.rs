@@ -1,11 +1,100 @@
 #![no_std]
+// Every method spell out the returned `impl Future` to keep the signature the same as the trait declaration.
+#![allow(clippy::manual_async_fn)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod array;
+mod as_result;
+#[cfg(feature = "bench")]
+mod bench;
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "alloc")]
+mod coalesce;
+mod either;
+mod error;
+mod factory;
+mod fuse;
+#[cfg(feature = "std")]
+mod hook;
+mod iter;
+#[cfg(feature = "alloc")]
+mod join;
+mod limit;
+mod macros;
+mod maybe_async;
+mod nested;
+#[cfg(feature = "std")]
+mod panic;
+mod pinned;
+mod pipeline;
+mod race;
+mod ready;
+mod retry;
+#[cfg(feature = "serde")]
+mod serde_result;
+#[cfg(feature = "alloc")]
+mod shared;
+mod spawn;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(test)]
+mod test_util;
+mod time;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use array::*;
+pub use as_result::*;
+#[cfg(feature = "bench")]
+pub use bench::*;
+#[cfg(feature = "alloc")]
+pub use boxed::*;
+#[cfg(feature = "alloc")]
+pub use coalesce::*;
+pub use either::*;
+pub use error::*;
+pub use factory::*;
+#[cfg(feature = "std")]
+pub use hook::*;
+pub use iter::*;
+#[cfg(feature = "alloc")]
+pub use join::*;
+pub use limit::*;
+pub use maybe_async::*;
+pub use nested::*;
+#[cfg(feature = "std")]
+pub use panic::*;
+pub use pinned::*;
+pub use pipeline::*;
+pub use race::*;
+pub use ready::*;
+pub use retry::*;
+#[cfg(feature = "serde")]
+pub use serde_result::*;
+#[cfg(feature = "alloc")]
+pub use shared::*;
+pub use spawn::*;
+#[cfg(feature = "futures")]
+pub use stream::*;
+pub use time::*;
+#[cfg(feature = "alloc")]
+pub use vec::*;
+
 /// Add extra functionalities to the [Result] type.
 /// This trait provides a set of async versions of the standard [Result] methods.
 /// Unlike the standard methods, these methods accept async functions as arguments.
 /// It return a [Future] that resolves to the same result of standard [Result] counterpart.
 /// Unless async functions is needed, it is recommended to use the standard [Result] methods for performance reason.
+///
+/// The returned [Future] is [Send] whenever `T`, `E`, the captured arguments and the futures returned by the async functions are [Send].
+/// Methods that pass a reference into the async function, such as [ExtraResult::inspect_fut], hold that reference across an await point
+/// so they additionally require the referenced type to be [Sync].
 pub trait ExtraResult<T, E> {
     /// Same as [Result::map] but took async functions.
     /// 
@@ -35,6 +124,8 @@ pub trait ExtraResult<T, E> {
     /// 
     /// It calls the async function with the error value inside the [Result] if it is Err.
     /// If the [Result] is Ok, it return the same result as original.
+    /// With `std` feature, the error is also reported to the hook set by `set_error_hook` before calling the function.
+    /// The error doesn't need to be [Debug](core::fmt::Debug) so the hook get the name of its type, use `report_err_fut` to report the value.
     fn map_err_fut<F, U>(self, f: F) -> impl Future<Output = Result<T, U>>
     where
         F: AsyncFnOnce(E) -> U;
@@ -51,6 +142,7 @@ pub trait ExtraResult<T, E> {
     /// It calls the async function with the error value inside the [Result] if it is Err.
     /// If the [Result] is Ok, it return the same result as original.
     /// The function have no effect on the result of the [Result].
+    /// With `std` feature, the error is also reported to the hook set by `set_error_hook` the same way as [ExtraResult::map_err_fut].
     fn inspect_err_fut<F>(self, f: F) -> impl Future<Output = Self>
     where
         F: AsyncFnOnce(&E);
@@ -76,12 +168,24 @@ pub trait ExtraResult<T, E> {
     fn unwrap_or_else_fut<F>(self, f: F) -> impl Future<Output = T>
     where
         F: AsyncFnOnce(E) -> T;
+    /// Same as [Result::unwrap_or] but took a future as default value.
+    /// 
+    /// It returns the value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, it awaits the `default` future and returns its output.
+    /// The error value is discarded.
+    /// 
+    /// Note that the `default` future is constructed eagerly by the caller but it is polled only on the Err path.
+    /// On Ok, the `default` future is dropped without being polled.
+    fn unwrap_or_fut<Fut>(self, default: Fut) -> impl Future<Output = T>
+    where
+        Fut: Future<Output = T>;
     /// Check if the [Result] is Ok and apply the async function to it.
     /// This is a mirror implementation of [Result::is_ok_and] but for async functions.
     /// 
     /// It calls the async function with the value inside the [Result] if it is Ok.
     /// If the [Result] is Err, it return false.
     /// The function must return a boolean value.
+    #[allow(clippy::wrong_self_convention)]
     fn is_ok_and_fut<F>(self, f: F) -> impl Future<Output = bool>
     where
         F: AsyncFnOnce(&T) -> bool;
@@ -91,9 +195,744 @@ pub trait ExtraResult<T, E> {
     /// It calls the async function with the error value inside the [Result] if it is Err.
     /// If the [Result] is Ok, it return false.
     /// The function must return a boolean value.
+    #[allow(clippy::wrong_self_convention)]
     fn is_err_and_fut<F>(self, f: F) -> impl Future<Output = bool>
     where
         F: AsyncFnOnce(&E) -> bool;
+    /// Pair the value of this [Result] with the value of another [Result].
+    /// This is a mirror implementation of [Option::zip] but for [Result].
+    /// 
+    /// It returns `Ok((t, u))` if both [Result] are Ok.
+    /// If any of them is Err, it returns the first error encountered.
+    /// If both of them are Err, the error of `self` wins and the error of `other` is dropped.
+    fn zip_fut<U>(self, other: Result<U, E>) -> impl Future<Output = Result<(T, U), E>>;
+    /// Combine the value of this [Result] with the value of another [Result] using async function.
+    /// This is a mirror implementation of [Option::zip_with] but for [Result] and async functions.
+    /// 
+    /// It calls the async function with both values if both [Result] are Ok.
+    /// If any of them is Err, it returns the first error encountered without calling the function.
+    /// If both of them are Err, the error of `self` wins and the error of `other` is dropped.
+    fn zip_with_fut<U, R, F>(self, other: Result<U, E>, f: F) -> impl Future<Output = Result<R, E>>
+    where
+        F: AsyncFnOnce(T, U) -> R;
+    /// Selectively recover from an error with async function.
+    /// 
+    /// It calls the async function with the error value inside the [Result] if it is Err.
+    /// The function returns `Ok(v)` to recover from the error or `Err(e)` to rethrow the error.
+    /// If the [Result] is Ok, it return the same result as original.
+    /// 
+    /// It is the same as [ExtraResult::or_else_fut] but the error type is kept the same
+    /// to make the intention of error handling code clearer.
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// 
+    /// #[derive(Debug, PartialEq)]
+    /// enum FetchError {
+    ///     NotFound,
+    ///     Unreachable,
+    /// }
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let recover = async |e| match e {
+    ///     // Missing entry can be recovered with an empty value.
+    ///     FetchError::NotFound => Ok(0),
+    ///     // Everything else is unrecoverable so it is rethrown.
+    ///     e => Err(e),
+    /// };
+    /// assert_eq!(Err(FetchError::NotFound).recover_fut(recover).await, Ok(0));
+    /// assert_eq!(Err::<u8, _>(FetchError::Unreachable).recover_fut(recover).await, Err(FetchError::Unreachable));
+    /// # }
+    /// ```
+    fn recover_fut<F>(self, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce(E) -> Result<T, E>;
+    /// Validate the value inside the [Result] with async function.
+    /// 
+    /// It calls the async function with the reference to the value inside the [Result] if it is Ok.
+    /// If the function returns Err, the value is dropped and the error is returned.
+    /// If the function returns Ok, it return the same result as original.
+    /// If the [Result] is Err, it returns the error without calling the function.
+    /// 
+    /// Unlike [ExtraResult::inspect_fut], the function can turn an Ok into an Err.
+    fn validate_fut<F>(self, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce(&T) -> Result<(), E>;
+    /// Inspect the whole [Result] with async function.
+    /// 
+    /// It calls the async function with the reference to the [Result] regardless of whether it is Ok or Err.
+    /// The function have no effect on the result of the [Result].
+    /// 
+    /// Unlike [ExtraResult::inspect_fut] and [ExtraResult::inspect_err_fut], a single function sees both cases.
+    /// It is useful for uniform logging or metrics of an outcome.
+    fn tap_fut<F>(self, f: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(&Result<T, E>);
+    /// Same as [Result::ok] but return a [Future].
+    /// 
+    /// It returns `Some(v)` if the [Result] is Ok and None if it is Err.
+    /// It doesn't await anything. The returned future is ready on the first poll.
+    /// It exists to keep a chain of async combinators fluent.
+    fn ok_fut(self) -> impl Future<Output = Option<T>>;
+    /// Same as [Result::err] but return a [Future].
+    /// 
+    /// It returns `Some(e)` if the [Result] is Err and None if it is Ok.
+    /// It doesn't await anything. The returned future is ready on the first poll.
+    /// It exists to keep a chain of async combinators fluent.
+    fn err_fut(self) -> impl Future<Output = Option<E>>;
+    /// Check if the value inside the [Result] matches an async predicate.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, it returns false without calling the function.
+    /// 
+    /// It behaves the same as [ExtraResult::is_ok_and_fut] but it is named for a membership check,
+    /// e.g. comparing the value against a target fetched asynchronously.
+    fn contains_fut<F>(self, f: F) -> impl Future<Output = bool>
+    where
+        F: AsyncFnOnce(&T) -> bool;
+    /// Check if the error inside the [Result] matches an async predicate.
+    /// 
+    /// It calls the async function with the error value inside the [Result] if it is Err.
+    /// If the [Result] is Ok, it returns false without calling the function.
+    /// 
+    /// It behaves the same as [ExtraResult::is_err_and_fut] but it is named for a membership check,
+    /// e.g. comparing the error against a target fetched asynchronously.
+    fn contains_err_fut<F>(self, f: F) -> impl Future<Output = bool>
+    where
+        F: AsyncFnOnce(&E) -> bool;
+    /// Fold both cases of the [Result] into a single value with async functions.
+    /// 
+    /// It calls the `ok` function with the value inside the [Result] if it is Ok.
+    /// It calls the `err` function with the error value inside the [Result] if it is Err.
+    /// Exactly one of the functions is awaited.
+    /// 
+    /// It is the same as [ExtraResult::map_or_else_fut] with the functions in the order of Ok then Err.
+    fn fold_fut<U, Fo, Fe>(self, ok: Fo, err: Fe) -> impl Future<Output = U>
+    where
+        Fo: AsyncFnOnce(T) -> U,
+        Fe: AsyncFnOnce(E) -> U;
+    /// Turn the [Result] into a [Stream](futures::Stream) that yields the [Result] once.
+    /// 
+    /// It is useful to feed a single [Result] into a stream pipeline, e.g. with [ExtraResultStream::map_results].
+    /// This method is only available with `futures` feature.
+    #[cfg(feature = "futures")]
+    fn into_result_stream(self) -> impl futures::Stream<Item = Result<T, E>>;
+    /// Map the value inside the [Result] with async function that can fail.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok and return its result.
+    /// If the [Result] is Err, it return the same result as original.
+    /// 
+    /// It is equivalent to [ExtraResult::and_then_fut]. The name is for those who look for a "try map".
+    fn try_map_fut<U, F>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> Result<U, E>;
+    /// Same as [Result::unwrap_or_default] but return a [Future].
+    /// 
+    /// It returns the value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, the error is dropped and `T::default()` is returned.
+    /// The default value is constructed only when the [Result] is Err.
+    fn unwrap_or_default_fut(self) -> impl Future<Output = T>
+    where
+        T: Default;
+    /// Same as [ExtraResult::map_fut] but it can be cancelled by a token.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and polls the returned future concurrently with the `token` future.
+    /// If the mapping completes first, it returns Ok with the mapped value.
+    /// If the `token` completes first, the mapping future is dropped and it returns `Err(Cancelled::Cancelled)`.
+    /// The mapping future is polled before the `token` so if both are ready on the same poll, the mapping wins.
+    /// If the [Result] is Err, it returns `Err(Cancelled::Inner(e))` without polling the `token`.
+    /// 
+    /// Any future can be used as a token, e.g. a receiver of a oneshot channel mapped to `()`.
+    /// No runtime is needed.
+    fn map_fut_cancelable<U, F, C>(self, token: C, f: F) -> impl Future<Output = Result<U, Cancelled<E>>>
+    where
+        C: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [ExtraResult::map_fut] but the mapping must complete before the `sleep` future.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and polls the returned future concurrently with the `sleep` future.
+    /// If the mapping completes first, it returns Ok with the mapped value.
+    /// If the `sleep` completes first, the mapping future is dropped and it returns `Err(TimeoutOr::Elapsed)`.
+    /// The mapping future is polled before the `sleep` so if both are ready on the same poll, the mapping wins.
+    /// If the [Result] is Err, it returns `Err(TimeoutOr::Inner(e))` immediately without polling the `sleep`.
+    /// 
+    /// The `sleep` future can come from any runtime, e.g. `tokio::time::sleep(duration)`.
+    fn map_fut_timeout<U, F, S>(self, sleep: S, f: F) -> impl Future<Output = Result<U, TimeoutOr<E>>>
+    where
+        S: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [Result::as_ref] but return a [Future].
+    /// 
+    /// It borrows the [Result] so the async combinators can run over the borrowed contents
+    /// without consuming the original [Result].
+    /// The returned future and its output borrow `self` for the lifetime `'a`.
+    fn as_ref_fut<'a>(&'a self) -> impl Future<Output = Result<&'a T, &'a E>>
+    where
+        T: 'a,
+        E: 'a;
+    /// Same as [Result::as_mut] but return a [Future].
+    /// 
+    /// It mutably borrows the [Result] so the async combinators can run over the borrowed contents
+    /// without consuming the original [Result].
+    /// The returned future and its output borrow `self` for the lifetime `'a`.
+    fn as_mut_fut<'a>(&'a mut self) -> impl Future<Output = Result<&'a mut T, &'a mut E>>
+    where
+        T: 'a,
+        E: 'a;
+    /// Turn the [Result] into a [ResultFuture] so async combinators can be chained without awaiting each step.
+    /// 
+    /// The chained combinators run in order only when the final [ResultFuture] is awaited.
+    fn into_pipeline(self) -> ResultFuture<T, E>;
+    /// Same as [ExtraResult::map_err_fut] but pass an extra context value to the async function.
+    /// 
+    /// It calls the async function with the error value inside the [Result] and the `ctx` if it is Err.
+    /// If the [Result] is Ok, the `ctx` is dropped unused and it return the same result as original.
+    /// 
+    /// It is useful to attach a value known before the operation, e.g. a request id, to the error
+    /// without capturing it in the function beforehand.
+    fn map_err_with_fut<C, U, F>(self, ctx: C, f: F) -> impl Future<Output = Result<T, U>>
+    where
+        F: AsyncFnOnce(E, C) -> U;
+    /// Record the outcome of the [Result] as a `tracing` event.
+    /// 
+    /// It emits an `INFO` event if the [Result] is Ok or an `ERROR` event with the [Display](core::fmt::Display)
+    /// of the error if it is Err. Both events carry `name` field with `span_name` value and `outcome` field.
+    /// The [Result] is returned unchanged.
+    /// 
+    /// It replaces a pair of [ExtraResult::inspect_fut] and [ExtraResult::inspect_err_fut] that log the result.
+    /// This method is only available with `tracing` feature.
+    #[cfg(feature = "tracing")]
+    fn inspect_traced_fut(self, span_name: &'static str) -> impl Future<Output = Self>
+    where
+        E: core::fmt::Display;
+    /// Mutate the value inside the [Result] in place with async function.
+    /// 
+    /// It calls the async function with the mutable reference to the value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, it return the same result as original.
+    /// 
+    /// It is clearer than [ExtraResult::map_fut] when the value is only mutated and no new value is constructed.
+    fn and_modify_fut<F>(self, f: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(&mut T);
+    /// Attach a context computed by async function to the error while keeping the original error.
+    /// 
+    /// It calls the async function with the reference to the error value inside the [Result] if it is Err
+    /// and returns `Err((context, e))`.
+    /// If the [Result] is Ok, it returns the value without calling the function.
+    /// 
+    /// Unlike a type erased error context, the original error keeps its type.
+    fn context_fut<C, F>(self, f: F) -> impl Future<Output = Result<T, (C, E)>>
+    where
+        F: AsyncFnOnce(&E) -> C;
+    /// Same as [ExtraResult::map_fut] but the async function is dispatched through a [Spawn].
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and hand the returned future to the `spawner`.
+    /// If the [Result] is Err, it returns the error without touching the `spawner`.
+    /// 
+    /// It is useful to move heavy async work to a dedicated pool without depending on a specific runtime.
+    fn map_fut_on<U, F, Sp>(self, spawner: Sp, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Sp: Spawn,
+        F: AsyncFnOnce(T) -> U;
+    /// Map the value inside the [Result] with async function only if an async predicate holds.
+    /// 
+    /// It calls the `predicate` with the reference to the value inside the [Result] if it is Ok.
+    /// If the `predicate` returns true, it calls `f` with the value and returns Ok with the new value.
+    /// If the `predicate` returns false, it returns the value unchanged without calling `f`.
+    /// If the [Result] is Err, it returns the error without calling any of the functions.
+    fn map_fut_if<F, P>(self, predicate: P, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        P: AsyncFnOnce(&T) -> bool,
+        F: AsyncFnOnce(T) -> T;
+    /// Lift the [Result] into a [Future] that is ready immediately.
+    /// 
+    /// It is the canonical way to start an async pipeline from a concrete [Result],
+    /// e.g. to pass it where a [Future] of [Result] is expected.
+    /// The returned future resolves to the [Result] on the first poll.
+    fn ready(self) -> impl Future<Output = Result<T, E>>;
+    /// Same as [ExtraResult::map_or_else_fut] but the `default` function is sync.
+    /// 
+    /// The `default` function is called with the error value if the [Result] is Err.
+    /// The async function `f` is called with the value inside the [Result] if it is Ok.
+    /// It avoid wrapping a trivial sync fallback in an async block.
+    fn map_or_sync_fut<U, D, F>(self, default: D, f: F) -> impl Future<Output = U>
+    where
+        D: FnOnce(E) -> U,
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [ExtraResult::map_or_else_fut] but the `f` function is sync.
+    /// 
+    /// The async function `default` is called with the error value if the [Result] is Err.
+    /// The `f` function is called with the value inside the [Result] if it is Ok.
+    fn map_sync_or_else_fut<U, D, F>(self, default: D, f: F) -> impl Future<Output = U>
+    where
+        D: AsyncFnOnce(E) -> U,
+        F: FnOnce(T) -> U;
+    /// Turn the [Result] into a [TryFuture](futures::TryFuture) that resolves to the [Result].
+    /// 
+    /// It is useful to feed a [Result] into [TryFutureExt](futures::TryFutureExt) combinators such as `and_then`.
+    /// Any future returned by this crate that resolves to a [Result] is already a [TryFuture](futures::TryFuture).
+    /// This method is only available with `futures` feature.
+    #[cfg(feature = "futures")]
+    fn into_try_future(self) -> impl futures::TryFuture<Ok = T, Error = E>;
+    /// Same as [ExtraResult::map_fut] but the async function also get a mutable reference to an external state.
+    /// 
+    /// It calls the async function with the `state` and the value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, the `state` is untouched and it return the same error as original.
+    /// Passing the state explicitly avoid capturing it in the closure, e.g. to keep a running counter outside the chain.
+    fn scan_fut<S, U, F>(self, state: &mut S, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(&mut S, T) -> U;
+    /// Lift the [Result] into an instrumented [Future] and return its [PollStats] along.
+    /// 
+    /// It is the same as calling [instrument_fut] with [ExtraResult::ready].
+    /// Use [instrument_fut] to instrument a whole chain of combinators.
+    /// This method is only available with `bench` feature.
+    #[cfg(feature = "bench")]
+    fn instrumented_fut(self) -> (impl Future<Output = Result<T, E>>, PollStats);
+    /// Merge both arms of the [Result] into a single type with async functions.
+    /// 
+    /// It calls the `ok` function with the value inside the [Result] if it is Ok.
+    /// It calls the `err` function with the error value inside the [Result] if it is Err.
+    /// 
+    /// It is the same as [ExtraResult::fold_fut] and [ExtraResult::map_or_else_fut] but the argument order is Ok then Err.
+    /// Beware that [ExtraResult::map_or_else_fut] take the Err function first, like [Result::map_or_else].
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// 
+    /// #[derive(Debug, PartialEq)]
+    /// struct Response {
+    ///     status: u16,
+    ///     body: String,
+    /// }
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let respond = async |r: Result<String, String>| r.merge_fut(
+    ///     async |body| Response { status: 200, body },
+    ///     async |e| Response { status: 500, body: format!("internal error: {e}") },
+    /// ).await;
+    /// assert_eq!(respond(Ok("hello".to_owned())).await, Response { status: 200, body: "hello".to_owned() });
+    /// assert_eq!(respond(Err("db down".to_owned())).await, Response { status: 500, body: "internal error: db down".to_owned() });
+    /// # }
+    /// ```
+    fn merge_fut<U, Fo, Fe>(self, ok: Fo, err: Fe) -> impl Future<Output = U>
+    where
+        Fo: AsyncFnOnce(T) -> U,
+        Fe: AsyncFnOnce(E) -> U;
+    /// Ensure the value inside the [Result] satisfy an async condition.
+    /// 
+    /// It calls the async function with a reference to the value inside the [Result] if it is Ok.
+    /// If the condition is false, the value is replaced with `Err(err)`. Otherwise, it return the same result as original.
+    /// If the [Result] is Err, it return the same result as original and `err` is dropped.
+    fn ensure_fut<F>(self, cond: F, err: E) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce(&T) -> bool;
+    /// Push the error into an external collector with async function and erase it from the [Result].
+    /// 
+    /// It calls the async function with the error value inside the [Result] if it is Err and return `Err(())`.
+    /// If the [Result] is Ok, it return the value as Ok without calling the function.
+    /// It is useful to run many independent operations with an uniform `Result<T, ()>` shape
+    /// while collecting their errors into a single place, e.g. a channel.
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// use std::sync::mpsc;
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let (tx, rx) = mpsc::channel();
+    /// let sink = async |e| tx.send(e).unwrap();
+    /// assert_eq!(Ok::<u8, &str>(1).accumulate_err_fut(sink).await, Ok(1));
+    /// assert_eq!(Err::<u8, _>("timeout").accumulate_err_fut(sink).await, Err(()));
+    /// assert_eq!(Err::<u8, _>("refused").accumulate_err_fut(sink).await, Err(()));
+    /// assert_eq!(rx.try_iter().collect::<Vec<_>>(), ["timeout", "refused"]);
+    /// # }
+    /// ```
+    fn accumulate_err_fut<F>(self, sink: F) -> impl Future<Output = Result<T, ()>>
+    where
+        F: AsyncFnOnce(E);
+    /// Map the value inside the [Result] with async function only if `enabled` is true.
+    /// 
+    /// If the [Result] is Ok and `enabled` is true, it calls the async function `f` with the value.
+    /// If the [Result] is Ok and `enabled` is false, it calls the sync function `identity` with the value instead.
+    /// If the [Result] is Err, it return the same error as original without calling any of them.
+    /// It is useful to toggle an expensive async transformation at runtime without duplicating the chain.
+    fn maybe_map_fut<U, F, I>(self, enabled: bool, f: F, identity: I) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> U,
+        I: FnOnce(T) -> U;
+    /// Report the error inside the [Result] to the global error hook set by [set_error_hook].
+    /// 
+    /// It calls the hook with the error value inside the [Result] if it is Err and return the same result as original.
+    /// If no hook is set or the [Result] is Ok, it does nothing.
+    /// It is meant to be chained at the places where errors should be centrally logged.
+    /// This method is only available with `std` feature.
+    #[cfg(feature = "std")]
+    fn report_err_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        E: core::fmt::Debug;
+    /// Same as [ExtraResult::map_fut] but the async function run inside the `tracing` span that is current at the call.
+    /// 
+    /// The current [Span](tracing::Span) is captured when this method is called, not when the future is first polled.
+    /// The returned future enter the span on every poll, so the async function keep the caller's span context
+    /// even if the future is moved to another task.
+    /// The span is only recorded if a `tracing` subscriber is installed.
+    /// This method is only available with `tracing` feature.
+    #[cfg(feature = "tracing")]
+    fn map_fut_in_span<U, F>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> U;
+    /// Inspect the value inside the [Result] with async function that is dispatched through a [SpawnDetached] and not awaited.
+    /// 
+    /// It calls the async function with a clone of the value inside the [Result] if it is Ok
+    /// and hand the returned future to the `spawner` which run it in the background.
+    /// It return the same result as original immediately. If the [Result] is Err, nothing is spawned.
+    /// 
+    /// The async function receive an owned clone of the value because the inspection may outlive the main chain.
+    /// Hence `T` must be [Clone] and both `T` and the function must be `'static`.
+    /// It is meant for fire-and-forget side effect such as telemetry that shouldn't block the main path.
+    fn inspect_spawned_fut<F, Sp>(self, spawner: Sp, f: F) -> impl Future<Output = Self>
+    where
+        T: Clone + 'static,
+        Sp: SpawnDetached,
+        F: AsyncFnOnce(T) + 'static;
+    /// Same as [Result::is_ok_and] but the function take a reference. It is here so every "and" check is discoverable through this trait.
+    /// 
+    /// It calls the sync function with the reference to the value inside the [Result] if it is Ok and return its result.
+    /// If the [Result] is Err, it return false without calling the function.
+    /// Prefer it over [ExtraResult::is_ok_and_fut] when the predicate is sync.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_ok_and_sync<F>(self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool;
+    /// Same as [Result::is_err_and] but the function take a reference. It is here so every "and" check is discoverable through this trait.
+    /// 
+    /// It calls the sync function with the reference to the error value inside the [Result] if it is Err and return its result.
+    /// If the [Result] is Ok, it return false without calling the function.
+    /// Prefer it over [ExtraResult::is_err_and_fut] when the predicate is sync.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_err_and_sync<F>(self, f: F) -> bool
+    where
+        F: FnOnce(&E) -> bool;
+    /// Swap the Ok and the Err of the [Result].
+    /// 
+    /// It turns `Ok(t)` into `Err(t)` and `Err(e)` into `Ok(e)`.
+    /// It is the async analogue of a hypothetical `Result::swap`, useful for inverted-polarity flows
+    /// where the interesting case is modeled as an error upstream.
+    /// The swap itself is sync but returning a [Future] keep it composable inside an async chain.
+    fn swap_fut(self) -> impl Future<Output = Result<E, T>>;
+    /// Map a borrowed value inside the [Result] with async function without consuming the [Result].
+    /// 
+    /// It calls the async function with the reference to the value inside the [Result] if it is Ok and return `Some` of its output.
+    /// If the [Result] is Err, it return `None` without calling the function.
+    /// The returned future borrows `self` for the lifetime `'a`, so the output `U` may also borrow from the value.
+    fn map_ref_fut<'a, U, F>(&'a self, f: F) -> impl Future<Output = Option<U>>
+    where
+        T: 'a,
+        E: 'a,
+        F: AsyncFnOnce(&'a T) -> U;
+    /// Join this [Result] with another [Result], collecting every error.
+    /// 
+    /// It returns `Ok((t, u))` if both of them are Ok.
+    /// Otherwise, it returns all the errors in a [Vec](alloc::vec::Vec), the error of `self` first.
+    /// Unlike [ExtraResult::zip_fut] which keep only the first error, no error is dropped, e.g. for validation reporting.
+    /// This method is only available with `alloc` feature.
+    #[cfg(feature = "alloc")]
+    fn join_fut<U>(self, other: Result<U, E>) -> impl Future<Output = Result<(T, U), alloc::vec::Vec<E>>>;
+    /// Same as [ExtraResult::map_fut] but also measure how long the async function took.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and return the mapped [Result] along with the elapsed [Duration](core::time::Duration) measured by [Instant](std::time::Instant).
+    /// If the [Result] is Err, it return the same error as original with a zero duration.
+    /// The duration is a wall clock time so it include the time the future spent waiting for other tasks.
+    /// This method is only available with `std` feature.
+    #[cfg(feature = "std")]
+    fn timed_fut<U, F>(self, f: F) -> impl Future<Output = (Result<U, E>, core::time::Duration)>
+    where
+        F: AsyncFnOnce(T) -> U;
+    /// Log the error inside the [Result] and its whole [source](core::error::Error::source) chain.
+    /// 
+    /// If the [Result] is Err, it walks the error and its sources, from the outermost to the innermost.
+    /// With `tracing` feature, each of them is emitted as an `ERROR` event with its `depth` in the chain.
+    /// With `std` feature, each of them is also reported to the global hook set by [set_error_hook].
+    /// Without any of these features, nothing is logged.
+    /// 
+    /// Aside from the logging side effect, this is a pure pass-through. The [Result] is returned unchanged.
+    fn source_chain_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        E: core::error::Error;
+    /// Run an async cleanup after the [Result] is produced, regardless of whether it is Ok or Err.
+    /// 
+    /// It always calls the async function and return the same result as original once it completed.
+    /// It models a `try/finally` for async teardown, e.g. releasing a lease.
+    /// Unlike [ExtraResult::tap_fut], the cleanup receive nothing since it should be independent of the outcome.
+    /// The cleanup is guaranteed to run as long as the returned future is polled to completion.
+    fn finally_fut<F>(self, cleanup: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce();
+    /// Same as [ExtraResult::map_fut_timeout] but the deadline is borrowed so it can be shared by a sequence of maps.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok and race it against `deadline`.
+    /// If `deadline` completes first, the mapping future is dropped and it returns `Err(TimeoutOr::Elapsed)`.
+    /// If the [Result] is Err, it returns `Err(TimeoutOr::Inner(e))` without polling `deadline`.
+    /// 
+    /// The same pinned deadline can be passed to each step with [Pin::as_mut](core::pin::Pin::as_mut)
+    /// so every step only get the time that remains. The deadline is polled only while a step is running.
+    /// Once it elapsed, it must not be passed to another step unless it is safe to poll after completion,
+    /// e.g. a timer that stay ready. Otherwise, stop the sequence at the first `TimeoutOr::Elapsed`.
+    fn with_deadline_fut<U, F, S>(self, deadline: core::pin::Pin<&mut S>, f: F) -> impl Future<Output = Result<U, TimeoutOr<E>>>
+    where
+        S: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [ExtraResult::map_fut] but a panic in the async function is caught and converted into [Panicked].
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok.
+    /// If the function completed normally, it returns `Ok(Ok(u))`.
+    /// If the function panicked, either when it was called or when its future was polled, it returns `Ok(Err(Panicked))`.
+    /// If the [Result] is Err, it return the same error as original.
+    /// 
+    /// It is the same as mapping with [catch_fut] so the same `panic = "unwind"` requirement apply.
+    /// This method is only available with `std` feature.
+    #[cfg(feature = "std")]
+    fn map_catch_fut<U, F>(self, f: F) -> impl Future<Output = Result<Result<U, Panicked>, E>>
+    where
+        F: AsyncFnOnce(T) -> U;
+    /// Route the value inside the [Result] to an async handler chosen by a key.
+    /// 
+    /// If the [Result] is Ok, it extracts a key from the value with `key_of`, looks up a handler with `table`
+    /// and calls the handler with the value. It returns Ok with the output of the handler.
+    /// If the [Result] is Err, it return the same error as original without calling any of them.
+    /// 
+    /// Every handler returned by `table` must have the same type, e.g. an async closure that capture the key
+    /// or a function pointer that return a boxed future.
+    fn dispatch_fut<K, U, F, Kf, Tf>(self, key_of: Kf, table: Tf) -> impl Future<Output = Result<U, E>>
+    where
+        Kf: FnOnce(&T) -> K,
+        Tf: FnOnce(K) -> F,
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [ExtraResult::unwrap_or_else_fut] but for the error side.
+    /// 
+    /// It returns the error value inside the [Result] if it is Err.
+    /// If the [Result] is Ok, it calls the async function with the value and return its output as an error.
+    /// It is useful to turn an unexpected success into an error for an uniform error reporting.
+    fn unwrap_err_or_else_fut<F>(self, f: F) -> impl Future<Output = E>
+    where
+        F: AsyncFnOnce(T) -> E;
+    /// Compute a derived value from the value inside the [Result] with async function and keep the original value.
+    /// 
+    /// It calls the async function with the reference to the value inside the [Result] if it is Ok
+    /// and returns `Ok((value, derived))`.
+    /// If the [Result] is Err, it return the same error as original.
+    /// 
+    /// The value is only borrowed while the derived value is computed then it is returned as owned,
+    /// so `T` doesn't need to be [Clone].
+    fn map_keep_fut<U, F>(self, f: F) -> impl Future<Output = Result<(T, U), E>>
+    where
+        F: AsyncFnOnce(&T) -> U;
+    /// Same as [ExtraResult::map_fut] but the async function also get a borrowed `sink` to emit events into.
+    /// 
+    /// It calls the async function with the value inside the [Result] and a reference to `sink` if it is Ok.
+    /// If the [Result] is Err, it return the same error as original, nothing is emitted and `sink` is dropped.
+    /// Pass a reference, e.g. `&sender`, as `sink` to keep using it after the map.
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// use std::sync::mpsc;
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let (progress, rx) = mpsc::channel();
+    /// let upload = async |chunks: u8, progress: &&mpsc::Sender<u8>| {
+    ///     for chunk in 1..=chunks {
+    ///         progress.send(chunk).unwrap();
+    ///     }
+    ///     chunks
+    /// };
+    /// assert_eq!(Ok::<u8, ()>(3).map_with_sink_fut(&progress, upload).await, Ok(3));
+    /// assert_eq!(Err::<u8, ()>(()).map_with_sink_fut(&progress, upload).await, Err(()));
+    /// assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// # }
+    /// ```
+    fn map_with_sink_fut<U, S, F>(self, sink: S, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T, &S) -> U;
+    /// Apply a sync function to the whole [Result] inside an async chain.
+    /// 
+    /// It calls the sync function with the [Result] when the returned future is polled for the first time and resolves to its output.
+    /// The future is ready on that first poll.
+    /// It is the bridge between sync and async steps, e.g. to call any method of [Result] in the middle of a chain
+    /// without an `async move` wrapper.
+    fn then_sync<U, F>(self, f: F) -> impl Future<Output = U>
+    where
+        F: FnOnce(Result<T, E>) -> U;
+    /// Run a fallible async step and pair its error with a snapshot taken before the step, for a rollback.
+    /// 
+    /// If the [Result] is Ok, it takes a snapshot of the value with `snapshot` then calls the async function with the value.
+    /// If the step returns Ok, the snapshot is dropped and it returns the new value.
+    /// If the step returns Err, it returns `Err((e, Some(snapshot)))` so the caller can roll back to the state before the step.
+    /// If the [Result] is Err, it returns `Err((e, None))` without taking a snapshot since there is nothing to roll back.
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let withdraw = async |balance: u32| if balance >= 50 { Ok(balance - 50) } else { Err("insufficient fund") };
+    /// assert_eq!(Ok::<u32, &str>(80).checkpoint_fut(|balance| *balance, withdraw).await, Ok(30));
+    /// // The step failed so the caller can roll back to the balance before the step.
+    /// assert_eq!(Ok::<u32, &str>(30).checkpoint_fut(|balance| *balance, withdraw).await, Err(("insufficient fund", Some(30))));
+    /// # }
+    /// ```
+    fn checkpoint_fut<S, K, F>(self, snapshot: K, f: F) -> impl Future<Output = Result<T, (E, Option<S>)>>
+    where
+        K: FnOnce(&T) -> S,
+        F: AsyncFnOnce(T) -> Result<T, E>;
+    /// Recover from an error with a value from async function and keep the [Result] for further chaining.
+    /// 
+    /// It awaits the async function if the [Result] is Err and return Ok with its output.
+    /// The error value is discarded.
+    /// If the [Result] is Ok, it return the same result as original without calling the function.
+    /// 
+    /// Unlike [ExtraResult::unwrap_or_else_fut], it doesn't unwrap the value so other combinators can be chained after it.
+    fn or_default_fut<F>(self, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce() -> T;
+    /// Same as [ExtraResult::map_fut] but call `heartbeat` every interval while the mapping is running.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and polls the returned future concurrently with a loop that awaits a sleep made by `interval_sleep` then calls `heartbeat`.
+    /// The loop yields once after each heartbeat so a sleep that is ready immediately doesn't starve the mapping or the executor.
+    /// The heartbeats stop once the mapping completed so `heartbeat` is never called after it returns Ok.
+    /// The mapping future is polled before the sleep so if both are ready on the same poll, no more heartbeat is made.
+    /// If the [Result] is Err, it return the same result as original without sleeping or calling `heartbeat`.
+    /// 
+    /// It is useful to keep a connection alive or to report progress while a long mapping looks idle.
+    fn map_with_heartbeat_fut<U, F, Q, S, H>(self, interval_sleep: Q, heartbeat: H, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Q: Fn() -> S,
+        S: Future<Output = ()>,
+        H: FnMut(),
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [ExtraResult::ready] but return a [ReadyResult] which is a named [Future] type.
+    /// 
+    /// The returned future resolves to the [Result] on the first poll.
+    /// It is [Unpin] so it can be polled without pinning and stored in a typed slot alongside other futures.
+    fn into_ready_named(self) -> ReadyResult<T, E>;
+    /// Same as [ExtraResult::map_fut] but record the error into `errors` instead of returning it.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok and return `Some` of its output.
+    /// If the [Result] is Err, it pushes the error into `errors` and return `None` without calling the function.
+    /// The same `errors` can be passed to many calls to collect every failure of a best-effort pipeline instead of stopping at the first one.
+    /// This method is only available with `alloc` feature.
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// 
+    /// async fn lookup(id: u8) -> Result<u8, &'static str> {
+    ///     if id % 2 == 0 { Ok(id) } else { Err("not found") }
+    /// }
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut errors = Vec::new();
+    /// let name = lookup(2).await.map_fut_lenient(async |x| x + 10, &mut errors).await;
+    /// let group = lookup(3).await.map_fut_lenient(async |x| x + 20, &mut errors).await;
+    /// let role = lookup(5).await.map_fut_lenient(async |x| x + 30, &mut errors).await;
+    /// assert_eq!((name, group, role), (Some(12), None, None));
+    /// assert_eq!(errors, ["not found", "not found"]);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn map_fut_lenient<U, F>(self, f: F, errors: &mut alloc::vec::Vec<E>) -> impl Future<Output = Option<U>>
+    where
+        F: AsyncFnOnce(T) -> U;
+    /// Convert the [Result] into a [ControlFlow](core::ops::ControlFlow) to drive an async loop.
+    /// 
+    /// It maps `Ok(t)` to `Continue(t)` and `Err(e)` to `Break(e)`, the same as `?` which continue on Ok and break out on Err.
+    /// The returned future is ready immediately.
+    /// The inverse is [from_control_flow_fut].
+    fn into_control_flow_fut(self) -> impl Future<Output = core::ops::ControlFlow<E, T>>;
+    /// Same as [ExtraResult::map_fut_timeout] but return Ok with the `fallback` value if the `sleep` future completes first.
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and polls the returned future concurrently with the `sleep` future.
+    /// If the mapping completes first, it returns Ok with the mapped value.
+    /// If the `sleep` completes first, it returns Ok with the `fallback` value, e.g. a stale value from a cache.
+    /// The mapping future is dropped at that point so the mapping is cancelled at its last `.await`, it doesn't keep running in the background.
+    /// The mapping future is polled before the `sleep` so if both are ready on the same poll, the mapping wins.
+    /// If the [Result] is Err, it return the same error as original without polling the `sleep` and the `fallback` is dropped.
+    fn map_fut_or_stale<U, F, S>(self, sleep: S, fallback: U, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        S: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U;
+    /// Tag the error inside the [Result] with a sequence number taken from `counter`.
+    /// 
+    /// If the [Result] is Err, it atomically fetches the current value of `counter`, increments it and return the fetched number along with the error.
+    /// The number is taken when the returned future is polled so a future that is dropped without being polled doesn't consume a number.
+    /// Each error gets a distinct number even if many futures share the same `counter` concurrently, e.g. to correlate errors across tasks.
+    /// If the [Result] is Ok, it return the same value as original and `counter` is untouched.
+    /// The number wraps around on overflow.
+    /// This method is only available on targets with 64 bits atomic.
+    #[cfg(target_has_atomic = "64")]
+    fn enumerate_err_fut(self, counter: &core::sync::atomic::AtomicU64) -> impl Future<Output = Result<T, (u64, E)>>;
+    /// Same as [ExtraResult::map_fut] but accept either a sync or an async closure.
+    /// 
+    /// It calls the function with the value inside the [Result] if it is Ok and return Ok with its output.
+    /// If the [Result] is Err, it return the same error as original without calling the function.
+    /// A call site keeps working when the closure is changed from sync into async or the other way around.
+    /// 
+    /// With a sync closure, the returned future is ready on the first poll without any extra state.
+    /// See [MaybeAsync] for the type annotations that may be needed.
+    /// 
+    /// # Example
+    /// ```
+    /// use extra_result::*;
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// assert_eq!(Ok::<u8, u8>(1).map_any_fut(|x: u8| x + 1).await, Ok(2));
+    /// let res: Result<u8, u8> = Ok::<u8, u8>(1).map_any_fut(async |x: u8| x + 1).await;
+    /// assert_eq!(res, Ok(2));
+    /// # }
+    /// ```
+    fn map_any_fut<U, F, M>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: MaybeAsync<T, U, M>;
+    /// Same as [ExtraResult::and_then_fut] but attach a context taken from the value to the error of the mapping.
+    /// 
+    /// It calls `make_ctx` with the reference to the value inside the [Result] if it is Ok, then calls the async function with the value.
+    /// If the async function returns Err, the error is paired with `Some` of the context, e.g. an identifier of the failed record.
+    /// If the [Result] is Err, there is no value to take a context from, so the error is paired with `None`
+    /// without calling any of the functions.
+    /// The context is dropped if the async function returns Ok.
+    /// 
+    /// See [ExtraResult::checkpoint_fut] for the same idea when the mapping doesn't change the type of value.
+    fn map_fut_or_context<U, C, K, F>(self, make_ctx: K, f: F) -> impl Future<Output = Result<U, (Option<C>, E)>>
+    where
+        K: FnOnce(&T) -> C,
+        F: AsyncFnOnce(T) -> Result<U, E>;
+    /// Same as [ExtraResult::map_fut_on] but the async function is dispatched at the given [Priority] through a [PrioritizedSpawn].
+    /// 
+    /// It calls the async function with the value inside the [Result] if it is Ok
+    /// and hand the returned future to the `spawner` with `prio`.
+    /// If the [Result] is Err, it returns the error without touching the `spawner`.
+    /// 
+    /// It is useful when some transforms are latency-critical and others are background work, e.g. with QoS tiers.
+    fn map_fut_with_priority<U, F, Sp>(self, spawner: Sp, prio: Priority, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Sp: PrioritizedSpawn,
+        F: AsyncFnOnce(T) -> U;
 }
 
 impl<T, E> ExtraResult<T, E> for Result<T, E> {
@@ -150,7 +989,11 @@ impl<T, E> ExtraResult<T, E> for Result<T, E> {
         async {
             match self {
                 Ok(v) => Ok(v),
-                Err(e) => Err(f(e).await),
+                Err(e) => {
+                    #[cfg(feature = "std")]
+                    hook::report_observed::<E>("map_err_fut");
+                    Err(f(e).await)
+                }
             }
         }
     }
@@ -177,6 +1020,8 @@ impl<T, E> ExtraResult<T, E> for Result<T, E> {
     {
         async move {
             if let Err(ref e) = self {
+                #[cfg(feature = "std")]
+                hook::report_observed::<E>("inspect_err_fut");
                 f(e).await;
             }
             self
@@ -224,6 +1069,20 @@ impl<T, E> ExtraResult<T, E> for Result<T, E> {
             }
         }
     }
+    /// Unwrap a [Result] or await the given default future.
+    /// This is a mirror implementation of [Result::unwrap_or] but for async default value.
+    #[inline]
+    fn unwrap_or_fut<Fut>(self, default: Fut) -> impl Future<Output = T>
+    where
+        Fut: Future<Output = T>,
+    {
+        async {
+            match self {
+                Ok(v) => v,
+                Err(_) => default.await,
+            }
+        }
+    }
     /// Check if the [Result] is Ok and apply the async function to it.
     /// This is a mirror implementation of [Result::is_ok_and] but for async functions.
     #[inline]
@@ -252,210 +1111,2152 @@ impl<T, E> ExtraResult<T, E> for Result<T, E> {
             }
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn map_on_ok() {
-        Result::<u8, ()>::Ok(1)
-            .map_fut(async |x| { x + 1 }).await
-            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    /// Pair two [Result] into a [Result] of tuple.
+    /// This is a mirror implementation of [Option::zip] but for [Result].
+    #[inline]
+    fn zip_fut<U>(self, other: Result<U, E>) -> impl Future<Output = Result<(T, U), E>> {
+        async {
+            match (self, other) {
+                (Ok(t), Ok(u)) => Ok((t, u)),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            }
+        }
     }
-    #[tokio::test]
-    async fn map_on_err() {
-        Result::<u8, u8>::Err(1)
-            .map_fut(async |x| { x + 1 }).await
-            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    /// Combine two [Result] into another [Result] with async function.
+    /// This is a mirror implementation of [Option::zip_with] but for async functions.
+    #[inline]
+    fn zip_with_fut<U, R, F>(self, other: Result<U, E>, f: F) -> impl Future<Output = Result<R, E>>
+    where
+        F: AsyncFnOnce(T, U) -> R,
+    {
+        async {
+            match (self, other) {
+                (Ok(t), Ok(u)) => Ok(f(t, u).await),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            }
+        }
     }
-    #[tokio::test]
-    async fn map_or_on_ok() {
-        assert_eq!(
-            Result::<u8, ()>::Ok(1)
-                .map_or_fut(3, async |x| { x + 1 }).await, 
-            2u8
-        );
+    /// Recover a [Result] from an error with async function.
+    /// This is a specialized version of [ExtraResult::or_else_fut] that keep the error type.
+    #[inline]
+    fn recover_fut<F>(self, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce(E) -> Result<T, E>,
+    {
+        self.or_else_fut(f)
     }
-    #[tokio::test]
-    async fn map_or_on_err() {
-        assert_eq!(
-            Result::<u8, u8>::Err(1)
-                .map_or_fut(3u8, async |x| { x + 1 }).await, 
-            3u8)
-        ;
+    /// Validate the value of a [Result] with async function.
+    /// This is similar to [ExtraResult::inspect_fut] but the function can fail.
+    #[inline]
+    fn validate_fut<F>(self, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce(&T) -> Result<(), E>,
+    {
+        async {
+            match self {
+                Ok(v) => f(&v).await.map(|_| v),
+                Err(e) => Err(e),
+            }
+        }
     }
-    #[tokio::test]
-    async fn map_or_else_on_ok() {
-        assert_eq!(
+    /// Inspect a [Result] with async function.
+    /// This is similar to [ExtraResult::inspect_fut] but it is called on both Ok and Err.
+    #[inline]
+    fn tap_fut<F>(self, f: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(&Result<T, E>),
+    {
+        async move {
+            f(&self).await;
+            self
+        }
+    }
+    /// Convert a [Result] into an [Option] of its value.
+    /// This is a mirror implementation of [Result::ok] but return a [Future].
+    #[inline]
+    fn ok_fut(self) -> impl Future<Output = Option<T>> {
+        core::future::ready(self.ok())
+    }
+    /// Convert a [Result] into an [Option] of its error.
+    /// This is a mirror implementation of [Result::err] but return a [Future].
+    #[inline]
+    fn err_fut(self) -> impl Future<Output = Option<E>> {
+        core::future::ready(self.err())
+    }
+    /// Check if the value of a [Result] matches an async predicate.
+    /// This is the same as [ExtraResult::is_ok_and_fut].
+    #[inline]
+    fn contains_fut<F>(self, f: F) -> impl Future<Output = bool>
+    where
+        F: AsyncFnOnce(&T) -> bool,
+    {
+        self.is_ok_and_fut(f)
+    }
+    /// Check if the error of a [Result] matches an async predicate.
+    /// This is the same as [ExtraResult::is_err_and_fut].
+    #[inline]
+    fn contains_err_fut<F>(self, f: F) -> impl Future<Output = bool>
+    where
+        F: AsyncFnOnce(&E) -> bool,
+    {
+        self.is_err_and_fut(f)
+    }
+    /// Fold a [Result] into a single value with async functions.
+    /// This is the same as [ExtraResult::map_or_else_fut].
+    #[inline]
+    fn fold_fut<U, Fo, Fe>(self, ok: Fo, err: Fe) -> impl Future<Output = U>
+    where
+        Fo: AsyncFnOnce(T) -> U,
+        Fe: AsyncFnOnce(E) -> U,
+    {
+        self.map_or_else_fut(err, ok)
+    }
+    /// Turn a [Result] into a single item [Stream](futures::Stream).
+    #[cfg(feature = "futures")]
+    #[inline]
+    fn into_result_stream(self) -> impl futures::Stream<Item = Result<T, E>> {
+        futures::stream::once(core::future::ready(self))
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that can fail.
+    /// This is the same as [ExtraResult::and_then_fut].
+    #[inline]
+    fn try_map_fut<U, F>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> Result<U, E>,
+    {
+        self.and_then_fut(f)
+    }
+    /// Unwrap a [Result] or return the default value.
+    /// This is a mirror implementation of [Result::unwrap_or_default] but return a [Future].
+    #[inline]
+    fn unwrap_or_default_fut(self) -> impl Future<Output = T>
+    where
+        T: Default,
+    {
+        core::future::ready(self.unwrap_or_default())
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that can be cancelled.
+    /// This is [ExtraResult::map_fut] racing against a cancellation token.
+    #[inline]
+    fn map_fut_cancelable<U, F, C>(self, token: C, f: F) -> impl Future<Output = Result<U, Cancelled<E>>>
+    where
+        C: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => match race::race(f(v), token).await {
+                    Either::Left(u) => Ok(u),
+                    Either::Right(()) => Err(Cancelled::Cancelled),
+                },
+                Err(e) => Err(Cancelled::Inner(e)),
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that has a deadline.
+    /// This is [ExtraResult::map_fut] racing against a timer.
+    #[inline]
+    fn map_fut_timeout<U, F, S>(self, sleep: S, f: F) -> impl Future<Output = Result<U, TimeoutOr<E>>>
+    where
+        S: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => match race::race(f(v), sleep).await {
+                    Either::Left(u) => Ok(u),
+                    Either::Right(()) => Err(TimeoutOr::Elapsed),
+                },
+                Err(e) => Err(TimeoutOr::Inner(e)),
+            }
+        }
+    }
+    /// Borrow the contents of a [Result].
+    /// This is a mirror implementation of [Result::as_ref] but return a [Future].
+    #[inline]
+    fn as_ref_fut<'a>(&'a self) -> impl Future<Output = Result<&'a T, &'a E>>
+    where
+        T: 'a,
+        E: 'a,
+    {
+        core::future::ready(self.as_ref())
+    }
+    /// Mutably borrow the contents of a [Result].
+    /// This is a mirror implementation of [Result::as_mut] but return a [Future].
+    #[inline]
+    fn as_mut_fut<'a>(&'a mut self) -> impl Future<Output = Result<&'a mut T, &'a mut E>>
+    where
+        T: 'a,
+        E: 'a,
+    {
+        core::future::ready(self.as_mut())
+    }
+    /// Wrap a [Result] into a [ResultFuture].
+    #[inline]
+    fn into_pipeline(self) -> ResultFuture<T, E> {
+        ResultFuture::new(core::future::ready(self))
+    }
+    /// Convert a [Result] into another [Result] with async error mapping function that take a context.
+    /// This is [ExtraResult::map_err_fut] with an extra context argument.
+    #[inline]
+    fn map_err_with_fut<C, U, F>(self, ctx: C, f: F) -> impl Future<Output = Result<T, U>>
+    where
+        F: AsyncFnOnce(E, C) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => Ok(v),
+                Err(e) => Err(f(e, ctx).await),
+            }
+        }
+    }
+    /// Record the outcome of a [Result] as a `tracing` event.
+    #[cfg(feature = "tracing")]
+    #[inline]
+    fn inspect_traced_fut(self, span_name: &'static str) -> impl Future<Output = Self>
+    where
+        E: core::fmt::Display,
+    {
+        async move {
+            match &self {
+                Ok(_) => tracing::info!(name = span_name, outcome = "ok"),
+                Err(e) => tracing::error!(name = span_name, outcome = "err", error = %e),
+            }
+            self
+        }
+    }
+    /// Mutate the value of a [Result] with async function.
+    /// This is similar to [ExtraResult::inspect_fut] but the function receive a mutable reference.
+    #[inline]
+    fn and_modify_fut<F>(mut self, f: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(&mut T),
+    {
+        async move {
+            if let Ok(ref mut v) = self {
+                f(v).await;
+            }
+            self
+        }
+    }
+    /// Attach a context to the error of a [Result] with async function.
+    #[inline]
+    fn context_fut<C, F>(self, f: F) -> impl Future<Output = Result<T, (C, E)>>
+    where
+        F: AsyncFnOnce(&E) -> C,
+    {
+        async {
+            match self {
+                Ok(v) => Ok(v),
+                Err(e) => Err((f(&e).await, e)),
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function run by a [Spawn].
+    #[inline]
+    fn map_fut_on<U, F, Sp>(self, spawner: Sp, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Sp: Spawn,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) => Ok(spawner.spawn(f(v)).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Conditionally map the value of a [Result] with async functions.
+    #[inline]
+    fn map_fut_if<F, P>(self, predicate: P, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        P: AsyncFnOnce(&T) -> bool,
+        F: AsyncFnOnce(T) -> T,
+    {
+        async {
+            match self {
+                Ok(v) if predicate(&v).await => Ok(f(v).await),
+                other => other,
+            }
+        }
+    }
+    /// Lift a [Result] into a ready [Future].
+    #[inline]
+    fn ready(self) -> impl Future<Output = Result<T, E>> {
+        core::future::ready(self)
+    }
+    /// Convert a [Result] into a value with sync fallback and async mapping function.
+    #[inline]
+    fn map_or_sync_fut<U, D, F>(self, default: D, f: F) -> impl Future<Output = U>
+    where
+        D: FnOnce(E) -> U,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => f(v).await,
+                Err(e) => default(e),
+            }
+        }
+    }
+    /// Convert a [Result] into a value with async fallback and sync mapping function.
+    #[inline]
+    fn map_sync_or_else_fut<U, D, F>(self, default: D, f: F) -> impl Future<Output = U>
+    where
+        D: AsyncFnOnce(E) -> U,
+        F: FnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => f(v),
+                Err(e) => default(e).await,
+            }
+        }
+    }
+    /// Turn a [Result] into a ready [TryFuture](futures::TryFuture).
+    #[cfg(feature = "futures")]
+    #[inline]
+    fn into_try_future(self) -> impl futures::TryFuture<Ok = T, Error = E> {
+        futures::future::ready(self)
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that access a mutable state.
+    #[inline]
+    fn scan_fut<S, U, F>(self, state: &mut S, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(&mut S, T) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) => Ok(f(state, v).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Lift a [Result] into a ready [Future] that record its [PollStats].
+    #[cfg(feature = "bench")]
+    #[inline]
+    fn instrumented_fut(self) -> (impl Future<Output = Result<T, E>>, PollStats) {
+        instrument_fut(self.ready())
+    }
+    /// Merge both arms of a [Result] into a single type with async functions.
+    /// This is the same as [ExtraResult::map_or_else_fut] with the functions in the order of Ok then Err.
+    #[inline]
+    fn merge_fut<U, Fo, Fe>(self, ok: Fo, err: Fe) -> impl Future<Output = U>
+    where
+        Fo: AsyncFnOnce(T) -> U,
+        Fe: AsyncFnOnce(E) -> U,
+    {
+        self.map_or_else_fut(err, ok)
+    }
+    /// Replace the value of a [Result] with an error if an async condition is false.
+    #[inline]
+    fn ensure_fut<F>(self, cond: F, err: E) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce(&T) -> bool,
+    {
+        async {
+            match self {
+                Ok(v) if cond(&v).await => Ok(v),
+                Ok(_) => Err(err),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Push the error of a [Result] into an async sink and replace it with `()`.
+    #[inline]
+    fn accumulate_err_fut<F>(self, sink: F) -> impl Future<Output = Result<T, ()>>
+    where
+        F: AsyncFnOnce(E),
+    {
+        async {
+            match self {
+                Ok(v) => Ok(v),
+                Err(e) => {
+                    sink(e).await;
+                    Err(())
+                }
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function if it is enabled, or a sync one otherwise.
+    #[inline]
+    fn maybe_map_fut<U, F, I>(self, enabled: bool, f: F, identity: I) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> U,
+        I: FnOnce(T) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) if enabled => Ok(f(v).await),
+                Ok(v) => Ok(identity(v)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Report the error of a [Result] to the global error hook.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn report_err_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        E: core::fmt::Debug,
+    {
+        async {
+            if let Err(e) = &self {
+                hook::report_err(e);
+            }
+            self
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function instrumented with the current span.
+    #[cfg(feature = "tracing")]
+    #[inline]
+    fn map_fut_in_span<U, F>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T) -> U,
+    {
+        use tracing::Instrument;
+        self.map_fut(f).instrument(tracing::Span::current())
+    }
+    /// Inspect the value of a [Result] with async function spawned through a [SpawnDetached] without awaiting it.
+    #[inline]
+    fn inspect_spawned_fut<F, Sp>(self, spawner: Sp, f: F) -> impl Future<Output = Self>
+    where
+        T: Clone + 'static,
+        Sp: SpawnDetached,
+        F: AsyncFnOnce(T) + 'static,
+    {
+        async move {
+            if let Ok(v) = &self {
+                spawner.spawn_detached(f(v.clone()));
+            }
+            self
+        }
+    }
+    /// Check if a [Result] is Ok and the value satisfy a sync predicate.
+    #[inline]
+    fn is_ok_and_sync<F>(self, f: F) -> bool
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        self.as_ref().is_ok_and(f)
+    }
+    /// Check if a [Result] is Err and the error satisfy a sync predicate.
+    #[inline]
+    fn is_err_and_sync<F>(self, f: F) -> bool
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        self.as_ref().is_err_and(f)
+    }
+    /// Swap the Ok and the Err of a [Result].
+    #[inline]
+    fn swap_fut(self) -> impl Future<Output = Result<E, T>> {
+        core::future::ready(match self {
+            Ok(v) => Err(v),
+            Err(e) => Ok(e),
+        })
+    }
+    /// Map a borrowed value of a [Result] with async function.
+    #[inline]
+    fn map_ref_fut<'a, U, F>(&'a self, f: F) -> impl Future<Output = Option<U>>
+    where
+        T: 'a,
+        E: 'a,
+        F: AsyncFnOnce(&'a T) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) => Some(f(v).await),
+                Err(_) => None,
+            }
+        }
+    }
+    /// Join two [Result] into a [Result] of tuple, collecting every error.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn join_fut<U>(self, other: Result<U, E>) -> impl Future<Output = Result<(T, U), alloc::vec::Vec<E>>> {
+        core::future::ready(match (self, other) {
+            (Ok(t), Ok(u)) => Ok((t, u)),
+            (Ok(_), Err(e)) | (Err(e), Ok(_)) => Err(alloc::vec![e]),
+            (Err(e1), Err(e2)) => Err(alloc::vec![e1, e2]),
+        })
+    }
+    /// Convert a [Result] into another [Result] with async mapping function and measure its duration.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn timed_fut<U, F>(self, f: F) -> impl Future<Output = (Result<U, E>, core::time::Duration)>
+    where
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => {
+                    let start = std::time::Instant::now();
+                    let u = f(v).await;
+                    (Ok(u), start.elapsed())
+                }
+                Err(e) => (Err(e), core::time::Duration::ZERO),
+            }
+        }
+    }
+    /// Log the error of a [Result] along with its source chain.
+    #[inline]
+    fn source_chain_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        E: core::error::Error,
+    {
+        async {
+            #[cfg(any(feature = "tracing", feature = "std"))]
+            if let Err(e) = &self {
+                let chain = core::iter::successors(Some(e as &dyn core::error::Error), |e| e.source());
+                #[cfg(feature = "tracing")]
+                for (depth, e) in chain.clone().enumerate() {
+                    tracing::error!(depth, error = %e);
+                }
+                #[cfg(feature = "std")]
+                for e in chain {
+                    hook::report_err(&e);
+                }
+            }
+            self
+        }
+    }
+    /// Run an async cleanup and return the [Result] unchanged.
+    #[inline]
+    fn finally_fut<F>(self, cleanup: F) -> impl Future<Output = Self>
+    where
+        F: AsyncFnOnce(),
+    {
+        async {
+            cleanup().await;
+            self
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function racing a shared deadline.
+    #[inline]
+    fn with_deadline_fut<U, F, S>(self, deadline: core::pin::Pin<&mut S>, f: F) -> impl Future<Output = Result<U, TimeoutOr<E>>>
+    where
+        S: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U,
+    {
+        self.map_fut_timeout(deadline, f)
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that may panic.
+    #[cfg(feature = "std")]
+    #[inline]
+    fn map_catch_fut<U, F>(self, f: F) -> impl Future<Output = Result<Result<U, Panicked>, E>>
+    where
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => Ok(catch_fut(async move || f(v).await).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async handler looked up by a key of the value.
+    #[inline]
+    fn dispatch_fut<K, U, F, Kf, Tf>(self, key_of: Kf, table: Tf) -> impl Future<Output = Result<U, E>>
+    where
+        Kf: FnOnce(&T) -> K,
+        Tf: FnOnce(K) -> F,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => {
+                    let handler = table(key_of(&v));
+                    Ok(handler(v).await)
+                }
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Unwrap the error of a [Result] or compute it from the value with async function.
+    #[inline]
+    fn unwrap_err_or_else_fut<F>(self, f: F) -> impl Future<Output = E>
+    where
+        F: AsyncFnOnce(T) -> E,
+    {
+        async {
+            match self {
+                Ok(v) => f(v).await,
+                Err(e) => e,
+            }
+        }
+    }
+    /// Convert a [Result] into a [Result] of the value and a derived value computed with async function.
+    #[inline]
+    fn map_keep_fut<U, F>(self, f: F) -> impl Future<Output = Result<(T, U), E>>
+    where
+        F: AsyncFnOnce(&T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => {
+                    let u = f(&v).await;
+                    Ok((v, u))
+                }
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that emit into a sink.
+    #[inline]
+    fn map_with_sink_fut<U, S, F>(self, sink: S, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(T, &S) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) => Ok(f(v, &sink).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Apply a sync function to a [Result] on the first poll of the returned [Future].
+    #[inline]
+    fn then_sync<U, F>(self, f: F) -> impl Future<Output = U>
+    where
+        F: FnOnce(Result<T, E>) -> U,
+    {
+        async move {
+            f(self)
+        }
+    }
+    /// Run a fallible async step on a [Result] and pair its error with a snapshot of the value.
+    #[inline]
+    fn checkpoint_fut<S, K, F>(self, snapshot: K, f: F) -> impl Future<Output = Result<T, (E, Option<S>)>>
+    where
+        K: FnOnce(&T) -> S,
+        F: AsyncFnOnce(T) -> Result<T, E>,
+    {
+        async {
+            match self {
+                Ok(v) => {
+                    let checkpoint = snapshot(&v);
+                    f(v).await.map_err(|e| (e, Some(checkpoint)))
+                }
+                Err(e) => Err((e, None)),
+            }
+        }
+    }
+    /// Replace an error with the output of async function.
+    #[inline]
+    fn or_default_fut<F>(self, f: F) -> impl Future<Output = Result<T, E>>
+    where
+        F: AsyncFnOnce() -> T,
+    {
+        async {
+            match self {
+                Ok(v) => Ok(v),
+                Err(_) => Ok(f().await),
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function and periodic heartbeat.
+    /// This is [ExtraResult::map_fut] racing against an endless loop of sleep and heartbeat.
+    #[inline]
+    fn map_with_heartbeat_fut<U, F, Q, S, H>(self, interval_sleep: Q, mut heartbeat: H, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Q: Fn() -> S,
+        S: Future<Output = ()>,
+        H: FnMut(),
+        F: AsyncFnOnce(T) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) => {
+                    let beats = async move {
+                        loop {
+                            interval_sleep().await;
+                            heartbeat();
+                            let mut yielded = false;
+                            core::future::poll_fn(|cx| {
+                                if core::mem::replace(&mut yielded, true) {
+                                    core::task::Poll::Ready(())
+                                } else {
+                                    cx.waker().wake_by_ref();
+                                    core::task::Poll::Pending
+                                }
+                            }).await;
+                        }
+                    };
+                    let raced: Either<U, core::convert::Infallible> = race::race(f(v), beats).await;
+                    match raced {
+                        Either::Left(u) => Ok(u),
+                        Either::Right(never) => match never {},
+                    }
+                }
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Lift a [Result] into a ready [Future] of a named type.
+    #[inline]
+    fn into_ready_named(self) -> ReadyResult<T, E> {
+        ReadyResult::new(self)
+    }
+    /// Convert a [Result] into an [Option] with async mapping function, pushing the error into a [Vec](alloc::vec::Vec).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn map_fut_lenient<U, F>(self, f: F, errors: &mut alloc::vec::Vec<E>) -> impl Future<Output = Option<U>>
+    where
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => Some(f(v).await),
+                Err(e) => {
+                    errors.push(e);
+                    None
+                }
+            }
+        }
+    }
+    /// Convert a [Result] into a [ControlFlow](core::ops::ControlFlow) in a ready [Future].
+    #[inline]
+    fn into_control_flow_fut(self) -> impl Future<Output = core::ops::ControlFlow<E, T>> {
+        core::future::ready(match self {
+            Ok(v) => core::ops::ControlFlow::Continue(v),
+            Err(e) => core::ops::ControlFlow::Break(e),
+        })
+    }
+    /// Convert a [Result] into another [Result] with async mapping function that fall back to a stale value on timeout.
+    /// This is [ExtraResult::map_fut] racing against a timer.
+    #[inline]
+    fn map_fut_or_stale<U, F, S>(self, sleep: S, fallback: U, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        S: Future<Output = ()>,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Ok(v) => match race::race(f(v), sleep).await {
+                    Either::Left(u) => Ok(u),
+                    Either::Right(()) => Ok(fallback),
+                },
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Pair the error of a [Result] with a sequence number from an atomic counter.
+    #[cfg(target_has_atomic = "64")]
+    #[inline]
+    fn enumerate_err_fut(self, counter: &core::sync::atomic::AtomicU64) -> impl Future<Output = Result<T, (u64, E)>> {
+        // Only the uniqueness of the number matter, it doesn't synchronize other memory.
+        async move {
+            self.map_err(|e| (counter.fetch_add(1, core::sync::atomic::Ordering::Relaxed), e))
+        }
+    }
+    /// Convert a [Result] into another [Result] with either sync or async mapping function.
+    #[inline]
+    fn map_any_fut<U, F, M>(self, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        F: MaybeAsync<T, U, M>,
+    {
+        async {
+            match self {
+                Ok(v) => Ok(f.call_fut(v).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+    /// Run a fallible async mapping on a [Result] and pair its error with a context taken from the value.
+    #[inline]
+    fn map_fut_or_context<U, C, K, F>(self, make_ctx: K, f: F) -> impl Future<Output = Result<U, (Option<C>, E)>>
+    where
+        K: FnOnce(&T) -> C,
+        F: AsyncFnOnce(T) -> Result<U, E>,
+    {
+        async {
+            match self {
+                Ok(v) => {
+                    let ctx = make_ctx(&v);
+                    f(v).await.map_err(|e| (Some(ctx), e))
+                }
+                Err(e) => Err((None, e)),
+            }
+        }
+    }
+    /// Convert a [Result] into another [Result] with async mapping function run by a [PrioritizedSpawn].
+    #[inline]
+    fn map_fut_with_priority<U, F, Sp>(self, spawner: Sp, prio: Priority, f: F) -> impl Future<Output = Result<U, E>>
+    where
+        Sp: PrioritizedSpawn,
+        F: AsyncFnOnce(T) -> U,
+    {
+        async move {
+            match self {
+                Ok(v) => Ok(spawner.spawn_with_priority(prio, f(v)).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+// The baseline tests compare booleans with `assert_eq!`.
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use test_util::never;
+
+    fn assert_send<F: Future + Send>(_: &F) {}
+
+    #[test]
+    fn futures_are_send() {
+        let ok = || Result::<u8, u8>::Ok(1);
+        assert_send(&ok().map_fut(async |x| x + 1));
+        assert_send(&ok().map_or_fut(0, async |x| x + 1));
+        assert_send(&ok().map_or_else_fut(async |x| x - 1, async |x| x + 1));
+        assert_send(&ok().map_err_fut(async |x| x + 1));
+        assert_send(&ok().inspect_fut(async |_| {}));
+        assert_send(&ok().inspect_err_fut(async |_| {}));
+        assert_send(&ok().and_then_fut(async |x| Ok(x + 1)));
+        assert_send(&ok().or_else_fut(async |x| Err::<u8, u8>(x + 1)));
+        assert_send(&ok().unwrap_or_else_fut(async |x| x + 1));
+        assert_send(&ok().unwrap_or_fut(async { 0 }));
+        assert_send(&ok().is_ok_and_fut(async |x| *x == 1));
+        assert_send(&ok().is_err_and_fut(async |x| *x == 1));
+        assert_send(&ok().zip_fut(ok()));
+        assert_send(&ok().zip_with_fut(ok(), async |x, y| x + y));
+        assert_send(&ok().recover_fut(async |x| Ok(x + 1)));
+        assert_send(&ok().validate_fut(async |_| Ok(())));
+        assert_send(&ok().tap_fut(async |_| {}));
+        assert_send(&ok().ok_fut());
+        assert_send(&ok().err_fut());
+        assert_send(&ok().contains_fut(async |x| *x == 1));
+        assert_send(&ok().contains_err_fut(async |x| *x == 1));
+        assert_send(&ok().fold_fut(async |x| x + 1, async |x| x - 1));
+        assert_send(&ok().try_map_fut(async |x| Ok(x + 1)));
+        assert_send(&ok().unwrap_or_default_fut());
+        assert_send(&ok().map_fut_cancelable(core::future::pending(), async |x| x + 1));
+        assert_send(&ok().map_fut_timeout(core::future::pending(), async |x| x + 1));
+        let mut res = ok();
+        assert_send(&res.as_ref_fut());
+        assert_send(&res.as_mut_fut());
+        assert_send(&ok().into_pipeline().map_fut(async |x| x + 1).and_then_fut(async |x| Ok(x + 1)));
+        assert_send(&ok().map_err_with_fut(1u8, async |x, c| x + c));
+        assert_send(&ok().and_modify_fut(async |x| *x += 1));
+        assert_send(&ok().context_fut(async |x| *x + 1));
+        assert_send(&ok().map_fut_if(async |x| *x == 1, async |x| x + 1));
+        assert_send(&ok().ready());
+        assert_send(&ok().map_or_sync_fut(|x| x - 1, async |x| x + 1));
+        assert_send(&ok().map_sync_or_else_fut(async |x| x - 1, |x| x + 1));
+        assert_send(&ok().scan_fut(&mut 0u8, async |s, x| { *s += x; *s }));
+        assert_send(&ok().merge_fut(async |x| x + 1, async |x| x - 1));
+        assert_send(&ok().ensure_fut(async |x| *x > 0, 0));
+        assert_send(&ok().accumulate_err_fut(async |_| {}));
+        assert_send(&ok().maybe_map_fut(true, async |x| x + 1, |x| x));
+        assert_send(&ok().inspect_spawned_fut(spawn::tests::EagerSpawner::default(), async |_| {}));
+        assert_send(&ok().swap_fut());
+        let borrowed = ok();
+        let plus_one = async |x: &u8| *x + 1;
+        assert_send(&borrowed.map_ref_fut(plus_one));
+        #[cfg(feature = "alloc")]
+        assert_send(&ok().join_fut(ok()));
+        #[cfg(feature = "std")]
+        assert_send(&ok().timed_fut(async |x| x + 1));
+        assert_send(&ok().finally_fut(async || {}));
+        #[cfg(feature = "std")]
+        assert_send(&ok().map_catch_fut(async |x| x + 1));
+        assert_send(&ok().dispatch_fut(|x| *x > 0, |positive| async move |x| if positive { x } else { 0 }));
+        assert_send(&ok().unwrap_err_or_else_fut(async |x| x + 1));
+        assert_send(&ok().map_keep_fut(async |x| *x + 1));
+        assert_send(&ok().then_sync(|r| r.ok()));
+        assert_send(&ok().checkpoint_fut(|x| *x, async |x| Ok(x + 1)));
+        assert_send(&ok().or_default_fut(async || 0));
+        assert_send(&ok().map_with_heartbeat_fut(core::future::pending, || {}, async |x| x + 1));
+        assert_send(&ok().into_ready_named());
+        assert_send(&ok().into_control_flow_fut());
+        assert_send(&ok().map_fut_or_stale(core::future::pending(), 0, async |x| x + 1));
+        #[cfg(target_has_atomic = "64")]
+        assert_send(&ok().enumerate_err_fut(&core::sync::atomic::AtomicU64::new(0)));
+        assert_send(&ok().map_any_fut(|x: u8| x + 1));
+        assert_send(&ok().map_any_fut::<u8, _, AsyncClosure>(async |x: u8| x + 1));
+        assert_send(&ok().map_fut_or_context(|x| *x, async |x| Ok(x + 1)));
+    }
+    #[tokio::test]
+    async fn spawn_combinator_future() {
+        let handle = tokio::spawn(
+            Result::<u8, u8>::Ok(1)
+                .map_fut(async |x| { tokio::task::yield_now().await; x + 1 })
+        );
+        assert_eq!(handle.await.unwrap(), Ok(2u8));
+    }
+
+    #[tokio::test]
+    async fn map_on_ok() {
+        Result::<u8, ()>::Ok(1)
+            .map_fut(async |x| { x + 1 }).await
+            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn map_on_err() {
+        Result::<u8, u8>::Err(1)
+            .map_fut(async |x| { x + 1 }).await
+            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    }
+    #[tokio::test]
+    async fn map_or_on_ok() {
+        assert_eq!(
+            Result::<u8, ()>::Ok(1)
+                .map_or_fut(3, async |x| { x + 1 }).await, 
+            2u8
+        );
+    }
+    #[tokio::test]
+    async fn map_or_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_or_fut(3u8, async |x| { x + 1 }).await, 
+            3u8)
+        ;
+    }
+    #[tokio::test]
+    async fn map_or_else_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_or_else_fut(async |x| x - 1, async |x| x + 1 ).await, 
+            2u8
+        );
+    }
+    #[tokio::test]
+    async fn map_or_else_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_or_else_fut(async |x| x - 1, async |x| x + 1 ).await, 
+            0u8
+        );
+    }
+    #[tokio::test]
+    async fn map_err_on_ok() {
+        Result::<u8, u8>::Ok(1)
+            .map_err_fut(async |x| { x + 1 }).await
+            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn map_err_on_err() {
+        Result::<u8, u8>::Err(1)
+            .map_err_fut(async |x| { x + 1 }).await
+            .map_err(|r| assert_eq!(r, 2u8)).unwrap_err();
+    }
+    #[tokio::test]
+    async fn inspect_on_ok() {
+        let mut val = 0;
+        Result::<u8, ()>::Ok(1)
+            .inspect_fut(async |_| { val = 1; }).await
+            .map(|r| assert_eq!(r, 1u8)).unwrap();
+        assert_eq!(val, 1);
+    }
+    #[tokio::test]
+    async fn inspect_on_err() {
+        Result::<u8, u8>::Err(1)
+            .inspect_fut(async |_| { panic!("This should never be called") }).await
+            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    }
+    #[tokio::test]
+    async fn inspect_err_on_ok() {
+        Result::<u8, u8>::Ok(1)
+            .inspect_err_fut(async |_| { panic!("This should never be called") }).await
+            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn inspect_err_on_err() {
+        let mut val = 0;
+        Result::<u8, u8>::Err(1)
+            .inspect_err_fut(async |_| { val = 1; }).await
+            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+        assert_eq!(val, 1);
+    }
+    #[tokio::test]
+    async fn and_then_on_ok() {
+        Result::<u8, ()>::Ok(1)
+            .and_then_fut(async |x| { Ok(x + 1) }).await
+            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn and_then_on_err() {
+        Result::<u8, u8>::Err(1)
+            .and_then_fut(async |x| { Ok(x + 1) }).await
+            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    }
+    #[tokio::test]
+    async fn or_else_ok_on_ok() {
+        Result::<u8, u8>::Ok(1)
+            .or_else_fut::<u8, _>(async |x| { Ok(x + 1) }).await
+            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn or_else_err_on_ok() {
+        Result::<u8, u8>::Ok(1)
+            .or_else_fut(async |x| { Err(x + 1) }).await // This should never be called
+            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn or_else_ok_on_err() {
+        Result::<u8, u8>::Err(1)
+            .or_else_fut::<u8, _>(async |x| { Ok(x + 1) }).await // Like a recovered error.
+            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    }
+    #[tokio::test]
+    async fn or_else_err_on_err() {
+        Result::<u8, u8>::Err(1)
+            .or_else_fut(async |x| { Err(x + 1) }).await
+            .map_err(|r| assert_eq!(r, 2u8)).unwrap_err();
+    }
+    #[tokio::test]
+    async fn unwrap_or_else_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .unwrap_or_else_fut(async |x| { x + 1 }).await, 
+            1u8
+        );
+    }
+    #[tokio::test]
+    async fn unwrap_or_else_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .unwrap_or_else_fut(async |x| { x + 1 }).await, 
+            2u8
+        );
+    }
+    #[tokio::test]
+    async fn is_ok_true_and_on_ok() {
+        assert_eq!(
+            Result::<u8, ()>::Ok(1)
+                .is_ok_and_fut(async |x| { *x == 1 }).await, 
+            true
+        );
+    }
+    #[tokio::test]
+    async fn is_ok_false_and_on_ok() {
+        assert_eq!(
+            Result::<u8, ()>::Ok(1)
+                .is_ok_and_fut(async |x| { *x != 1 }).await, 
+            false
+        );
+    }
+    #[tokio::test]
+    async fn is_ok_true_and_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .is_ok_and_fut(async |x| { *x == 1 }).await, 
+            false
+        );
+    }
+    #[tokio::test]
+    async fn is_ok_false_and_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .is_ok_and_fut(async |x| { *x != 1 }).await, 
+            false
+        );
+    }
+    #[tokio::test]
+    async fn is_err_true_and_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .is_err_and_fut(async |x| { *x == 1 }).await, 
+            false
+        );
+    }
+    #[tokio::test]
+    async fn is_err_false_and_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .is_err_and_fut(async |x| { *x != 1 }).await, 
+            false
+        );
+    }
+    #[tokio::test]
+    async fn is_err_true_and_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .is_err_and_fut(async |x| { *x == 1 }).await, 
+            true
+        );
+    }
+    #[tokio::test]
+    async fn is_err_false_and_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .is_err_and_fut(async |x| { *x != 1 }).await, 
+            false
+        );
+    }
+    #[tokio::test]
+    async fn unwrap_or_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .unwrap_or_fut(async { panic!("This should never be polled") }).await, 
+            1u8
+        );
+    }
+    #[tokio::test]
+    async fn unwrap_or_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .unwrap_or_fut(async { 3u8 }).await, 
+            3u8
+        );
+    }
+    #[tokio::test]
+    async fn zip_ok_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .zip_fut(Ok(2u16)).await, 
+            Ok((1u8, 2u16))
+        );
+    }
+    #[tokio::test]
+    async fn zip_err_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .zip_fut(Err::<u16, u8>(2)).await, 
+            Err(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn zip_ok_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .zip_fut(Ok(2u16)).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn zip_err_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .zip_fut(Err::<u16, u8>(2)).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn zip_with_ok_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .zip_with_fut(Ok(2u8), async |x, y| { x + y }).await, 
+            Ok(3u8)
+        );
+    }
+    #[tokio::test]
+    async fn zip_with_err_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .zip_with_fut(Err::<u8, u8>(2), async |_, _| -> u8 { panic!("This should never be called") }).await, 
+            Err(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn zip_with_ok_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .zip_with_fut(Ok(2u8), async |_, _| -> u8 { panic!("This should never be called") }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn zip_with_err_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .zip_with_fut(Err::<u8, u8>(2), async |_, _| -> u8 { panic!("This should never be called") }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn recover_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .recover_fut(async |_| { panic!("This should never be called") }).await, 
+            Ok(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn recover_recovered_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .recover_fut(async |x| { Ok(x + 1) }).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn recover_rethrown_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .recover_fut(async |x| { Err(x + 1) }).await, 
+            Err(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn validate_valid_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .validate_fut(async |_| { Ok(()) }).await, 
+            Ok(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn validate_invalid_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .validate_fut(async |x| { Err(x + 1) }).await, 
+            Err(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn validate_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .validate_fut(async |_| { panic!("This should never be called") }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn tap_on_ok() {
+        let mut val = None;
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .tap_fut(async |r| { val = Some(*r); }).await, 
+            Ok(1u8)
+        );
+        assert_eq!(val, Some(Ok(1u8)));
+    }
+    #[tokio::test]
+    async fn tap_on_err() {
+        let mut val = None;
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .tap_fut(async |r| { val = Some(*r); }).await, 
+            Err(1u8)
+        );
+        assert_eq!(val, Some(Err(1u8)));
+    }
+    #[tokio::test]
+    async fn ok_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).ok_fut().await, Some(1u8));
+    }
+    #[tokio::test]
+    async fn ok_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).ok_fut().await, None);
+    }
+    #[tokio::test]
+    async fn err_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).err_fut().await, None);
+    }
+    #[tokio::test]
+    async fn err_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).err_fut().await, Some(1u8));
+    }
+    #[tokio::test]
+    async fn contains_on_ok() {
+        let target = async || 1u8;
+        assert!(
+            Result::<u8, u8>::Ok(1)
+                .contains_fut(async |x| { *x == target().await }).await
+        );
+        assert!(
+            !Result::<u8, u8>::Ok(2)
+                .contains_fut(async |x| { *x == target().await }).await
+        );
+    }
+    #[tokio::test]
+    async fn contains_on_err() {
+        assert!(
+            !Result::<u8, u8>::Err(1)
+                .contains_fut(async |_| { panic!("This should never be called") }).await
+        );
+    }
+    #[tokio::test]
+    async fn contains_err_on_ok() {
+        assert!(
+            !Result::<u8, u8>::Ok(1)
+                .contains_err_fut(async |_| { panic!("This should never be called") }).await
+        );
+    }
+    #[tokio::test]
+    async fn contains_err_on_err() {
+        let target = async || 1u8;
+        assert!(
+            Result::<u8, u8>::Err(1)
+                .contains_err_fut(async |x| { *x == target().await }).await
+        );
+        assert!(
+            !Result::<u8, u8>::Err(2)
+                .contains_err_fut(async |x| { *x == target().await }).await
+        );
+    }
+    #[tokio::test]
+    async fn fold_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .fold_fut(async |x| x + 1, async |_| panic!("This should never be called")).await, 
+            2u8
+        );
+    }
+    #[tokio::test]
+    async fn fold_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .fold_fut(async |_| panic!("This should never be called"), async |x| x - 1).await, 
+            0u8
+        );
+    }
+    #[tokio::test]
+    async fn try_map_ok_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .try_map_fut(async |x| { Ok(x + 1) }).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn try_map_err_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .try_map_fut::<u8, _>(async |x| { Err(x + 1) }).await, 
+            Err(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn try_map_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .try_map_fut::<u8, _>(async |_| { panic!("This should never be called") }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn unwrap_or_default_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).unwrap_or_default_fut().await, 1u8);
+    }
+    #[tokio::test]
+    async fn unwrap_or_default_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).unwrap_or_default_fut().await, 0u8);
+    }
+    #[tokio::test]
+    async fn map_cancelable_completed_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_cancelable(core::future::pending(), async |x| { x + 1 }).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn map_cancelable_cancelled_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_cancelable(async {}, async |_| -> u8 { core::future::pending().await }).await, 
+            Err(Cancelled::Cancelled)
+        );
+    }
+    #[tokio::test]
+    async fn map_cancelable_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_fut_cancelable(async { panic!("This should never be polled") }, async |x| { x + 1 }).await, 
+            Err(Cancelled::Inner(1u8))
+        );
+    }
+    #[tokio::test]
+    async fn map_timeout_completed_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_timeout(core::future::pending(), async |x| { x + 1 }).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn map_timeout_elapsed_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_timeout(tokio::task::yield_now(), async |_| -> u8 { core::future::pending().await }).await, 
+            Err(TimeoutOr::Elapsed)
+        );
+    }
+    #[tokio::test]
+    async fn map_timeout_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_fut_timeout(async { panic!("This should never be polled") }, async |x| { x + 1 }).await, 
+            Err(TimeoutOr::Inner(1u8))
+        );
+    }
+    #[tokio::test]
+    async fn as_ref_on_ok() {
+        let res = Result::<u8, u8>::Ok(1);
+        assert_eq!(
+            res.as_ref_fut().await
+                .map_fut(async |x| { *x + 1 }).await, 
+            Ok(2u8)
+        );
+        assert_eq!(res, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn as_ref_on_err() {
+        let res = Result::<u8, u8>::Err(1);
+        assert_eq!(
+            res.as_ref_fut().await
+                .map_err_fut(async |x| { *x + 1 }).await, 
+            Err::<&u8, u8>(2u8)
+        );
+        assert_eq!(res, Err(1u8));
+    }
+    #[tokio::test]
+    async fn as_mut_on_ok() {
+        let mut res = Result::<u8, u8>::Ok(1);
+        res.as_mut_fut().await
+            .inspect_fut(async |_| {}).await
+            .map(|x| *x += 1).unwrap();
+        assert_eq!(res, Ok(2u8));
+    }
+    #[tokio::test]
+    async fn as_mut_on_err() {
+        let mut res = Result::<u8, u8>::Err(1);
+        res.as_mut_fut().await
+            .map_err(|x| *x += 1).unwrap_err();
+        assert_eq!(res, Err(2u8));
+    }
+    #[tokio::test]
+    async fn map_err_with_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_err_with_fut(2u8, async |_, _| -> u8 { panic!("This should never be called") }).await, 
+            Ok(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn map_err_with_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_err_with_fut(2u8, async |x, c| { (c, x) }).await, 
+            Err((2u8, 1u8))
+        );
+    }
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn inspect_traced_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).inspect_traced_fut("test").await, Ok(1u8));
+    }
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn inspect_traced_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).inspect_traced_fut("test").await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn and_modify_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .and_modify_fut(async |x| { tokio::task::yield_now().await; *x += 1; }).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn and_modify_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .and_modify_fut(async |_| { panic!("This should never be called") }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn context_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .context_fut(async |_| -> u8 { panic!("This should never be called") }).await, 
+            Ok(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn context_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .context_fut(async |x| { *x + 1 }).await, 
+            Err((2u8, 1u8))
+        );
+    }
+    #[tokio::test]
+    async fn map_on_spawner_on_ok() {
+        let spawner = spawn::tests::CountingSpawner::default();
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_on(&spawner, async |x| { x + 1 }).await, 
+            Ok(2u8)
+        );
+        assert_eq!(spawner.spawned.get(), 1);
+    }
+    #[tokio::test]
+    async fn map_on_spawner_on_err() {
+        let spawner = spawn::tests::CountingSpawner::default();
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_fut_on(&spawner, async |x| { x + 1 }).await, 
+            Err(1u8)
+        );
+        assert_eq!(spawner.spawned.get(), 0);
+    }
+    #[tokio::test]
+    async fn map_if_true_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_if(async |x| { *x == 1 }, async |x| { x + 1 }).await, 
+            Ok(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn map_if_false_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .map_fut_if(async |x| { *x != 1 }, async |_| { panic!("This should never be called") }).await, 
+            Ok(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn map_if_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .map_fut_if(async |_| { panic!("This should never be called") }, async |x| { x + 1 }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn ready_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).ready().await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn ready_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).ready().await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn ready_as_future() {
+        assert_eq!(
+            timeout_fut(time::tests::NeverSleeper, 10, Result::<u8, u8>::Ok(1).ready()).await, 
+            Ok(1u8)
+        );
+    }
+    #[tokio::test]
+    #[should_panic(expected = "This future should never be polled")]
+    async fn panic_on_poll_is_polled() {
+        never::<()>().await;
+    }
+    #[tokio::test]
+    async fn inactive_arm_never_polled_on_ok() {
+        let ok = || Result::<u8, u8>::Ok(1);
+        let spawner = spawn::tests::CountingSpawner::default();
+        ok().map_or_else_fut(async |_| never().await, async |x| x).await;
+        ok().map_or_sync_fut(|_| unreachable!(), async |x| x).await;
+        ok().map_sync_or_else_fut(async |_| never().await, |x| x).await;
+        ok().map_err_fut(async |_| never::<u8>().await).await.unwrap();
+        ok().inspect_err_fut(async |_| never().await).await.unwrap();
+        ok().or_else_fut(async |_| never::<Result<u8, u8>>().await).await.unwrap();
+        ok().unwrap_or_else_fut(async |_| never().await).await;
+        ok().unwrap_or_fut(never()).await;
+        assert!(!ok().is_err_and_fut(async |_| never().await).await);
+        ok().recover_fut(async |_| never().await).await.unwrap();
+        assert!(!ok().contains_err_fut(async |_| never().await).await);
+        ok().fold_fut(async |x| x, async |_| never().await).await;
+        ok().merge_fut(async |x| x, async |_| never().await).await;
+        ok().map_err_with_fut(1u8, async |_, _| never::<u8>().await).await.unwrap();
+        ok().context_fut(async |_| never::<u8>().await).await.unwrap();
+        ok().accumulate_err_fut(async |_| never().await).await.unwrap();
+        ok().map_fut_cancelable(core::future::pending(), async |x| x).await.unwrap();
+        ok().map_fut_timeout(core::future::pending(), async |x| x).await.unwrap();
+        ok().map_fut_if(async |_| false, async |_| never().await).await.unwrap();
+        ok().or_default_fut(async || never().await).await.unwrap();
+        ok().map_with_heartbeat_fut(core::future::pending, || unreachable!(), async |x| x).await.unwrap();
+        ok().map_fut_or_stale(core::future::pending(), 0, async |x| x).await.unwrap();
+        ok().map_fut_with_priority(&spawner, Priority::Low, async |x| x).await.unwrap();
+        ok().map_fut_on(&spawner, async |x| x).await.unwrap();
+    }
+    #[tokio::test]
+    async fn inactive_arm_never_polled_on_err() {
+        let err = || Result::<u8, u8>::Err(1);
+        let spawner = spawn::tests::CountingSpawner::default();
+        err().map_fut(async |_| never::<u8>().await).await.unwrap_err();
+        err().map_or_fut(0, async |_| never().await).await;
+        err().map_or_else_fut(async |x| x, async |_| never().await).await;
+        err().map_or_sync_fut(|x| x, async |_| never().await).await;
+        err().map_sync_or_else_fut(async |x| x, |_| unreachable!()).await;
+        err().scan_fut(&mut 0u8, async |_, _| never::<u8>().await).await.unwrap_err();
+        err().inspect_fut(async |_| never().await).await.unwrap_err();
+        err().and_then_fut(async |_| never::<Result<u8, u8>>().await).await.unwrap_err();
+        assert!(!err().is_ok_and_fut(async |_| never().await).await);
+        err().zip_with_fut(Ok(1u8), async |_, _| never::<u8>().await).await.unwrap_err();
+        err().validate_fut(async |_| never().await).await.unwrap_err();
+        assert!(!err().contains_fut(async |_| never().await).await);
+        err().fold_fut(async |_| never().await, async |x| x).await;
+        err().merge_fut(async |_| never().await, async |x| x).await;
+        err().ensure_fut(async |_| never().await, 0).await.unwrap_err();
+        err().maybe_map_fut(true, async |_| never::<u8>().await, |_| unreachable!()).await.unwrap_err();
+        err().try_map_fut(async |_| never::<Result<u8, u8>>().await).await.unwrap_err();
+        err().map_fut_cancelable(never(), async |_| never::<u8>().await).await.unwrap_err();
+        err().map_fut_timeout(never(), async |_| never::<u8>().await).await.unwrap_err();
+        err().and_modify_fut(async |_| never().await).await.unwrap_err();
+        err().map_fut_if(async |_| never().await, async |_| never().await).await.unwrap_err();
+        err().map_with_heartbeat_fut(core::future::pending, || unreachable!(), async |_| never::<u8>().await).await.unwrap_err();
+        err().map_fut_or_stale(never(), 0, async |_| never::<u8>().await).await.unwrap_err();
+        err().map_any_fut(|_: u8| -> u8 { unreachable!() }).await.unwrap_err();
+        err().map_any_fut::<u8, _, AsyncClosure>(async |_: u8| never::<u8>().await).await.unwrap_err();
+        err().map_fut_or_context(|_| -> u8 { unreachable!() }, async |_| never::<Result<u8, u8>>().await).await.unwrap_err();
+        err().map_fut_with_priority(&spawner, Priority::High, async |_| never::<u8>().await).await.unwrap_err();
+        err().map_fut_on(&spawner, async |_| never::<u8>().await).await.unwrap_err();
+        assert_eq!(spawner.spawned.get(), 0);
+    }
+    #[tokio::test]
+    async fn map_or_sync_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).map_or_sync_fut(|x| x - 1, async |x| x + 1).await, 2);
+    }
+    #[tokio::test]
+    async fn map_or_sync_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_or_sync_fut(|x| x - 1, async |x| x + 1).await, 0);
+    }
+    #[tokio::test]
+    async fn map_sync_or_else_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).map_sync_or_else_fut(async |x| x - 1, |x| x + 1).await, 2);
+    }
+    #[tokio::test]
+    async fn map_sync_or_else_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_sync_or_else_fut(async |x| x - 1, |x| x + 1).await, 0);
+    }
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn into_try_future_on_ok() {
+        use futures::TryFutureExt;
+        assert_eq!(
             Result::<u8, u8>::Ok(1)
-                .map_or_else_fut(async |x| x - 1, async |x| x + 1 ).await, 
-            2u8
+                .into_try_future()
+                .and_then(|x| Result::<u8, u8>::Ok(x + 1).map_fut(async |x| x + 1))
+                .await,
+            Ok(3u8)
         );
     }
+    #[cfg(feature = "futures")]
     #[tokio::test]
-    async fn map_or_else_on_err() {
+    async fn into_try_future_on_err() {
+        use futures::TryFutureExt;
         assert_eq!(
             Result::<u8, u8>::Err(1)
-                .map_or_else_fut(async |x| x - 1, async |x| x + 1 ).await, 
-            0u8
+                .into_try_future()
+                .map_err(|x| x + 1)
+                .and_then(|_| core::future::ready(Ok(0)))
+                .await,
+            Err(2u8)
         );
     }
     #[tokio::test]
-    async fn map_err_on_ok() {
-        Result::<u8, u8>::Ok(1)
-            .map_err_fut(async |x| { x + 1 }).await
-            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    async fn scan_on_ok() {
+        let mut total = 1u8;
+        assert_eq!(
+            Result::<u8, u8>::Ok(2)
+                .scan_fut(&mut total, async |s, x| { *s += x; x + 1 }).await, 
+            Ok(3u8)
+        );
+        assert_eq!(
+            Result::<u8, u8>::Ok(3)
+                .scan_fut(&mut total, async |s, x| { *s += x; x + 1 }).await, 
+            Ok(4u8)
+        );
+        assert_eq!(total, 6);
     }
     #[tokio::test]
-    async fn map_err_on_err() {
-        Result::<u8, u8>::Err(1)
-            .map_err_fut(async |x| { x + 1 }).await
-            .map_err(|r| assert_eq!(r, 2u8)).unwrap_err();
+    async fn scan_on_err() {
+        let mut total = 1u8;
+        assert_eq!(
+            Result::<u8, u8>::Err(2)
+                .scan_fut(&mut total, async |s, x| { *s += x; x + 1 }).await, 
+            Err(2u8)
+        );
+        assert_eq!(total, 1);
     }
+    #[cfg(feature = "bench")]
     #[tokio::test]
-    async fn inspect_on_ok() {
-        let mut val = 0;
-        Result::<u8, ()>::Ok(1)
-            .inspect_fut(async |_| { val = 1; }).await
-            .map(|r| assert_eq!(r, 1u8)).unwrap();
-        assert_eq!(val, 1);
+    async fn instrumented_on_ok() {
+        let (fut, stats) = Result::<u8, u8>::Ok(1).instrumented_fut();
+        assert_eq!(fut.await, Ok(1u8));
+        assert_eq!(stats.polls(), 1);
+        assert!(!stats.was_pending());
     }
+    #[cfg(feature = "bench")]
     #[tokio::test]
-    async fn inspect_on_err() {
-        Result::<u8, u8>::Err(1)
-            .inspect_fut(async |_| { panic!("This should never be called") }).await
-            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    async fn instrumented_on_err() {
+        let (fut, stats) = Result::<u8, u8>::Err(1).instrumented_fut();
+        assert_eq!(fut.await, Err(1u8));
+        assert_eq!(stats.polls(), 1);
     }
     #[tokio::test]
-    async fn inspect_err_on_ok() {
-        Result::<u8, u8>::Ok(1)
-            .inspect_err_fut(async |_| { panic!("This should never be called") }).await
-            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    async fn merge_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).merge_fut(async |x| x + 1, async |x| x - 1).await, 2);
     }
     #[tokio::test]
-    async fn inspect_err_on_err() {
-        let mut val = 0;
-        Result::<u8, u8>::Err(1)
-            .inspect_err_fut(async |_| { val = 1; }).await
-            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
-        assert_eq!(val, 1);
+    async fn merge_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).merge_fut(async |x| x + 1, async |x| x - 1).await, 0);
     }
     #[tokio::test]
-    async fn and_then_on_ok() {
-        Result::<u8, ()>::Ok(1)
-            .and_then_fut(async |x| { Ok(x + 1) }).await
-            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    async fn ensure_pass_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).ensure_fut(async |x| *x > 0, 0).await, Ok(1u8));
     }
     #[tokio::test]
-    async fn and_then_on_err() {
-        Result::<u8, u8>::Err(1)
-            .and_then_fut(async |x| { Ok(x + 1) }).await
-            .map_err(|r| assert_eq!(r, 1u8)).unwrap_err();
+    async fn ensure_fail_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).ensure_fut(async |x| *x > 1, 0).await, Err(0u8));
     }
     #[tokio::test]
-    async fn or_else_ok_on_ok() {
-        Result::<u8, u8>::Ok(1)
-            .or_else_fut::<u8, _>(async |x| { Ok(x + 1) }).await
-            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    async fn ensure_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).ensure_fut(async |_| never().await, 0).await, Err(1u8));
     }
     #[tokio::test]
-    async fn or_else_err_on_ok() {
-        Result::<u8, u8>::Ok(1)
-            .or_else_fut(async |x| { Err(x + 1) }).await // This should never be called
-            .map(|r| assert_eq!(r, 1u8)).unwrap();
+    async fn accumulate_err_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).accumulate_err_fut(async |_| never().await).await, Ok(1u8));
     }
     #[tokio::test]
-    async fn or_else_ok_on_err() {
-        Result::<u8, u8>::Err(1)
-            .or_else_fut::<u8, _>(async |x| { Ok(x + 1) }).await // Like a recovered error.
-            .map(|r| assert_eq!(r, 2u8)).unwrap();
+    async fn accumulate_err_on_err() {
+        let mut errors = [0u8; 2];
+        assert_eq!(Result::<u8, u8>::Err(1).accumulate_err_fut(async |e| errors[0] = e).await, Err(()));
+        assert_eq!(Result::<u8, u8>::Err(2).accumulate_err_fut(async |e| errors[1] = e).await, Err(()));
+        assert_eq!(errors, [1, 2]);
     }
     #[tokio::test]
-    async fn or_else_err_on_err() {
-        Result::<u8, u8>::Err(1)
-            .or_else_fut(async |x| { Err(x + 1) }).await
-            .map_err(|r| assert_eq!(r, 2u8)).unwrap_err();
+    async fn maybe_map_enabled_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).maybe_map_fut(true, async |x| x + 1, |x| x).await, Ok(2u8));
     }
     #[tokio::test]
-    async fn unwrap_or_else_on_ok() {
+    async fn maybe_map_disabled_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).maybe_map_fut(false, async |_| never().await, |x| x).await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn maybe_map_on_err() {
         assert_eq!(
-            Result::<u8, u8>::Ok(1)
-                .unwrap_or_else_fut(async |x| { x + 1 }).await, 
-            1u8
+            Result::<u8, u8>::Err(1).maybe_map_fut(true, async |_| never::<u8>().await, |_| unreachable!()).await, 
+            Err(1u8)
         );
     }
+    #[cfg(feature = "tracing")]
     #[tokio::test]
-    async fn unwrap_or_else_on_err() {
+    async fn map_in_span_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).map_fut_in_span(async |x| x + 1).await, Ok(2u8));
+    }
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn map_in_span_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_fut_in_span(async |_| never::<u8>().await).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn inspect_spawned_on_ok() {
+        static SEEN: core::sync::atomic::AtomicU8 = core::sync::atomic::AtomicU8::new(0);
+        let spawner = spawn::tests::EagerSpawner::default();
         assert_eq!(
-            Result::<u8, u8>::Err(1)
-                .unwrap_or_else_fut(async |x| { x + 1 }).await, 
-            2u8
+            Result::<u8, u8>::Ok(1).inspect_spawned_fut(&spawner, async |x| SEEN.store(x, core::sync::atomic::Ordering::Relaxed)).await, 
+            Ok(1u8)
         );
+        assert_eq!(SEEN.load(core::sync::atomic::Ordering::Relaxed), 1);
+        assert_eq!(spawner.completed.get(), 1);
     }
     #[tokio::test]
-    async fn is_ok_true_and_on_ok() {
+    async fn inspect_spawned_not_awaited() {
+        let spawner = spawn::tests::EagerSpawner::default();
         assert_eq!(
-            Result::<u8, ()>::Ok(1)
-                .is_ok_and_fut(async |x| { *x == 1 }).await, 
-            true
+            Result::<u8, u8>::Ok(1).inspect_spawned_fut(&spawner, async |_| core::future::pending().await).await, 
+            Ok(1u8)
         );
+        assert_eq!((spawner.spawned.get(), spawner.completed.get()), (1, 0));
     }
     #[tokio::test]
-    async fn is_ok_false_and_on_ok() {
+    async fn inspect_spawned_on_err() {
+        let spawner = spawn::tests::EagerSpawner::default();
         assert_eq!(
-            Result::<u8, ()>::Ok(1)
-                .is_ok_and_fut(async |x| { *x != 1 }).await, 
-            false
+            Result::<u8, u8>::Err(1).inspect_spawned_fut(&spawner, async |_| never().await).await, 
+            Err(1u8)
         );
+        assert_eq!(spawner.spawned.get(), 0);
+    }
+    #[test]
+    fn is_ok_and_sync_on_ok() {
+        assert!(Result::<u8, u8>::Ok(1).is_ok_and_sync(|x| *x == 1));
+        assert!(!Result::<u8, u8>::Ok(1).is_ok_and_sync(|x| *x == 2));
+    }
+    #[test]
+    fn is_ok_and_sync_on_err() {
+        assert!(!Result::<u8, u8>::Err(1).is_ok_and_sync(|_| unreachable!()));
+    }
+    #[test]
+    fn is_err_and_sync_on_ok() {
+        assert!(!Result::<u8, u8>::Ok(1).is_err_and_sync(|_| unreachable!()));
+    }
+    #[test]
+    fn is_err_and_sync_on_err() {
+        assert!(Result::<u8, u8>::Err(1).is_err_and_sync(|x| *x == 1));
+        assert!(!Result::<u8, u8>::Err(1).is_err_and_sync(|x| *x == 2));
     }
     #[tokio::test]
-    async fn is_ok_true_and_on_err() {
+    async fn swap_on_ok() {
+        assert_eq!(Result::<u8, i8>::Ok(1).swap_fut().await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn swap_on_err() {
+        assert_eq!(Result::<u8, i8>::Err(1).swap_fut().await, Ok(1i8));
+    }
+    #[tokio::test]
+    async fn map_ref_on_ok() {
+        let result = Result::<[u8; 2], u8>::Ok([1, 2]);
+        let first = result.map_ref_fut(async |x| &x[0]).await;
+        assert_eq!(first, Some(&1u8));
+        assert_eq!(result, Ok([1u8, 2]));
+    }
+    #[tokio::test]
+    async fn map_ref_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_ref_fut(async |_| never::<u8>().await).await, None);
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn join_ok_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).join_fut(Ok::<i8, u8>(2)).await, Ok((1u8, 2i8)));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn join_ok_err() {
+        assert_eq!(Result::<u8, u8>::Ok(1).join_fut(Err::<i8, u8>(2)).await, Err(alloc::vec![2u8]));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn join_err_ok() {
+        assert_eq!(Result::<u8, u8>::Err(1).join_fut(Ok::<i8, u8>(2)).await, Err(alloc::vec![1u8]));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn join_err_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).join_fut(Err::<i8, u8>(2)).await, Err(alloc::vec![1u8, 2]));
+    }
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn timed_on_ok() {
+        let (result, elapsed) = Result::<u8, u8>::Ok(1)
+            .timed_fut(async |x| { tokio::time::sleep(core::time::Duration::from_millis(5)).await; x + 1 }).await;
+        assert_eq!(result, Ok(2u8));
+        assert!(elapsed >= core::time::Duration::from_millis(5));
+    }
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn timed_on_err() {
         assert_eq!(
-            Result::<u8, u8>::Err(1)
-                .is_ok_and_fut(async |x| { *x == 1 }).await, 
-            false
+            Result::<u8, u8>::Err(1).timed_fut(async |_| never::<u8>().await).await, 
+            (Err(1u8), core::time::Duration::ZERO)
         );
     }
     #[tokio::test]
-    async fn is_ok_false_and_on_err() {
+    async fn source_chain_on_ok() {
+        assert_eq!(Result::<u8, Cancelled<TimeoutOr<core::fmt::Error>>>::Ok(1).source_chain_fut().await, Ok(1u8));
+    }
+    // With `std` feature, it is tested along with the error hook.
+    #[cfg(not(feature = "std"))]
+    #[tokio::test]
+    async fn source_chain_on_err() {
         assert_eq!(
-            Result::<u8, u8>::Err(1)
-                .is_ok_and_fut(async |x| { *x != 1 }).await, 
-            false
+            Result::<u8, _>::Err(Cancelled::Inner(TimeoutOr::Inner(core::fmt::Error))).source_chain_fut().await, 
+            Err(Cancelled::Inner(TimeoutOr::Inner(core::fmt::Error)))
         );
     }
     #[tokio::test]
-    async fn is_err_true_and_on_ok() {
+    async fn finally_on_ok() {
+        let mut cleaned = false;
+        assert_eq!(Result::<u8, u8>::Ok(1).finally_fut(async || cleaned = true).await, Ok(1u8));
+        assert!(cleaned);
+    }
+    #[tokio::test]
+    async fn finally_on_err() {
+        let mut cleaned = false;
+        assert_eq!(Result::<u8, u8>::Err(1).finally_fut(async || cleaned = true).await, Err(1u8));
+        assert!(cleaned);
+    }
+    #[tokio::test]
+    async fn with_deadline_shared_on_ok() {
+        let mut deadline = core::pin::pin!(core::future::pending::<()>());
+        let first = Result::<u8, u8>::Ok(1).with_deadline_fut(deadline.as_mut(), async |x| x + 1).await;
+        assert_eq!(first, Ok(2u8));
+        let second = Result::<u8, u8>::Ok(2).with_deadline_fut(deadline.as_mut(), async |x| x + 1).await;
+        assert_eq!(second, Ok(3u8));
+    }
+    #[tokio::test]
+    async fn with_deadline_elapsed() {
+        let mut deadline = core::pin::pin!(core::future::ready(()));
         assert_eq!(
-            Result::<u8, u8>::Ok(1)
-                .is_err_and_fut(async |x| { *x == 1 }).await, 
-            false
+            Result::<u8, u8>::Ok(1).with_deadline_fut(deadline.as_mut(), async |_| core::future::pending::<u8>().await).await, 
+            Err(TimeoutOr::Elapsed)
         );
     }
     #[tokio::test]
-    async fn is_err_false_and_on_ok() {
+    async fn with_deadline_on_err() {
+        let mut deadline = core::pin::pin!(never::<()>());
         assert_eq!(
-            Result::<u8, u8>::Ok(1)
-                .is_err_and_fut(async |x| { *x != 1 }).await, 
-            false
+            Result::<u8, u8>::Err(1).with_deadline_fut(deadline.as_mut(), async |_| never::<u8>().await).await, 
+            Err(TimeoutOr::Inner(1u8))
         );
     }
+    #[cfg(feature = "std")]
     #[tokio::test]
-    async fn is_err_true_and_on_err() {
+    async fn map_catch_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).map_catch_fut(async |x| x + 1).await, Ok(Ok(2u8)));
+    }
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn map_catch_panicked_on_ok() {
+        let result = Result::<u8, u8>::Ok(1).map_catch_fut(async |_| -> u8 { panic!("bad map") }).await;
+        assert_eq!(result.unwrap().unwrap_err().message(), Some("bad map"));
+    }
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn map_catch_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_catch_fut(async |_| never::<u8>().await).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn dispatch_on_ok() {
+        let route = |odd: bool| async move |x: u8| if odd { x * 2 } else { x + 1 };
+        assert_eq!(Result::<u8, u8>::Ok(3).dispatch_fut(|x| x % 2 == 1, route).await, Ok(6u8));
+        assert_eq!(Result::<u8, u8>::Ok(4).dispatch_fut(|x| x % 2 == 1, route).await, Ok(5u8));
+    }
+    #[tokio::test]
+    async fn dispatch_on_err() {
         assert_eq!(
             Result::<u8, u8>::Err(1)
-                .is_err_and_fut(async |x| { *x == 1 }).await, 
-            true
+                .dispatch_fut(|_| -> bool { unreachable!() }, |_| async |_| never::<u8>().await).await, 
+            Err(1u8)
         );
     }
     #[tokio::test]
-    async fn is_err_false_and_on_err() {
+    async fn unwrap_err_or_else_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).unwrap_err_or_else_fut(async |x| x + 1).await, 2u8);
+    }
+    #[tokio::test]
+    async fn unwrap_err_or_else_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).unwrap_err_or_else_fut(async |_| never().await).await, 1u8);
+    }
+    #[tokio::test]
+    async fn map_keep_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).map_keep_fut(async |x| *x + 1).await, Ok((1u8, 2u8)));
+    }
+    #[tokio::test]
+    async fn map_keep_without_clone() {
+        struct Record(u8);
+        let (record, derived) = Result::<Record, u8>::Ok(Record(1)).map_keep_fut(async |r| r.0 + 1).await.unwrap();
+        assert_eq!((record.0, derived), (1u8, 2u8));
+    }
+    #[tokio::test]
+    async fn map_keep_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_keep_fut(async |_| never::<u8>().await).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn map_with_sink_on_ok() {
+        let sink = core::cell::Cell::new(0u8);
         assert_eq!(
-            Result::<u8, u8>::Err(1)
-                .is_err_and_fut(async |x| { *x != 1 }).await, 
-            false
+            Result::<u8, u8>::Ok(1).map_with_sink_fut(&sink, async |x, s| { s.set(x); x + 1 }).await, 
+            Ok(2u8)
+        );
+        assert_eq!(sink.get(), 1);
+    }
+    #[tokio::test]
+    async fn map_with_sink_on_err() {
+        let sink = core::cell::Cell::new(0u8);
+        assert_eq!(
+            Result::<u8, u8>::Err(1).map_with_sink_fut(&sink, async |x, s| { s.set(x); x + 1 }).await, 
+            Err(1u8)
+        );
+        assert_eq!(sink.get(), 0);
+    }
+    #[tokio::test]
+    async fn then_sync_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).map_fut(async |x| x + 1).await.then_sync(|r| r.ok()).await, 
+            Some(2u8)
+        );
+    }
+    #[tokio::test]
+    async fn then_sync_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).then_sync(|r| r.unwrap_or_else(|e| e + 1)).await, 2u8);
+        drop(Result::<u8, u8>::Err(1).then_sync(|_| -> u8 { unreachable!() }));
+    }
+    #[tokio::test]
+    async fn checkpoint_step_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).checkpoint_fut(|x| *x, async |x| Ok(x + 1)).await, 
+            Ok::<u8, (u8, Option<u8>)>(2)
+        );
+    }
+    #[tokio::test]
+    async fn checkpoint_step_err() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).checkpoint_fut(|x| *x, async |x| Err(x + 1)).await, 
+            Err((2u8, Some(1u8)))
+        );
+    }
+    #[tokio::test]
+    async fn checkpoint_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1).checkpoint_fut(|_| -> u8 { unreachable!() }, async |_| never().await).await, 
+            Err((1u8, None))
+        );
+    }
+    #[tokio::test]
+    async fn or_default_on_ok() {
+        assert_eq!(Result::<u8, u8>::Ok(1).or_default_fut(async || 0).await, Ok(1));
+    }
+    #[tokio::test]
+    async fn or_default_discard_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1).or_default_fut(async || 0).await.map_fut(async |x| x + 1).await, 
+            Ok(1)
+        );
+    }
+    #[tokio::test]
+    async fn map_with_heartbeat_stop_after_map() {
+        let beats = core::cell::Cell::new(0u8);
+        let res = Result::<u8, u8>::Ok(1).map_with_heartbeat_fut(tokio::task::yield_now, || beats.set(beats.get() + 1), async |_| {
+            for _ in 0..3 {
+                tokio::task::yield_now().await;
+            }
+            beats.get()
+        }).await;
+        assert!(res.unwrap() > 0);
+        assert_eq!(res, Ok(beats.get()));
+    }
+    #[tokio::test]
+    async fn map_with_heartbeat_ready_sleep() {
+        let beats = core::cell::Cell::new(0u8);
+        let res = Result::<u8, u8>::Ok(1).map_with_heartbeat_fut(|| core::future::ready(()), || beats.set(beats.get() + 1), async |_| {
+            for _ in 0..3 {
+                tokio::task::yield_now().await;
+            }
+            beats.get()
+        }).await;
+        assert_eq!(res, Ok(3));
+    }
+    #[tokio::test]
+    async fn map_with_heartbeat_on_err() {
+        let mut beats = 0;
+        assert_eq!(
+            Result::<u8, u8>::Err(1).map_with_heartbeat_fut(tokio::task::yield_now, || beats += 1, async |x| x + 1).await, 
+            Err(1)
+        );
+        assert_eq!(beats, 0);
+    }
+    #[tokio::test]
+    async fn into_ready_named_in_slot() {
+        let slots: [ReadyResult<u8, u8>; 2] = [Ok(1).into_ready_named(), Err(2).into_ready_named()];
+        let mut outputs = [Ok(0); 2];
+        for (slot, output) in slots.into_iter().zip(outputs.iter_mut()) {
+            *output = slot.await;
+        }
+        assert_eq!(outputs, [Ok(1), Err(2)]);
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn map_lenient_collect_errors() {
+        let mut errors = alloc::vec::Vec::new();
+        assert_eq!(Result::<u8, u8>::Ok(1).map_fut_lenient(async |x| x + 1, &mut errors).await, Some(2));
+        assert_eq!(Result::<u8, u8>::Err(2).map_fut_lenient(async |x| x + 1, &mut errors).await, None);
+        assert_eq!(Result::<u8, u8>::Err(3).map_fut_lenient(async |_| never::<u8>().await, &mut errors).await, None);
+        assert_eq!(errors, [2, 3]);
+    }
+    #[tokio::test]
+    async fn into_control_flow_in_loop() {
+        let mut sum = 0u8;
+        for x in [Ok(1u8), Ok(2), Err(3), Ok(4)] {
+            match x.into_control_flow_fut().await {
+                core::ops::ControlFlow::Continue(v) => sum += v,
+                core::ops::ControlFlow::Break(e) => {
+                    assert_eq!(e, 3u8);
+                    break;
+                }
+            }
+        }
+        assert_eq!(sum, 3);
+    }
+    #[tokio::test]
+    async fn map_or_stale_in_time() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).map_fut_or_stale(core::future::ready(()), 0, async |x| x + 1).await, 
+            Ok(2)
+        );
+    }
+    #[tokio::test]
+    async fn map_or_stale_elapsed() {
+        struct Flag<'a>(&'a core::cell::Cell<bool>);
+        impl Drop for Flag<'_> {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+        let dropped = core::cell::Cell::new(false);
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).map_fut_or_stale(core::future::ready(()), 0, async |_| {
+                let _flag = Flag(&dropped);
+                core::future::pending::<u8>().await
+            }).await, 
+            Ok(0)
+        );
+        assert!(dropped.get());
+    }
+    #[cfg(target_has_atomic = "64")]
+    #[tokio::test]
+    async fn enumerate_err_on_ok() {
+        let counter = core::sync::atomic::AtomicU64::new(5);
+        assert_eq!(Result::<u8, u8>::Ok(1).enumerate_err_fut(&counter).await, Ok(1));
+        assert_eq!(counter.into_inner(), 5);
+    }
+    #[cfg(target_has_atomic = "64")]
+    #[tokio::test]
+    async fn enumerate_err_concurrently() {
+        let counter = core::sync::atomic::AtomicU64::new(0);
+        let task = async |x: u8| {
+            for _ in 0..x {
+                tokio::task::yield_now().await;
+            }
+            Result::<u8, u8>::Err(x).enumerate_err_fut(&counter).await
+        };
+        drop(Result::<u8, u8>::Err(9).enumerate_err_fut(&counter));
+        let (a, b, c) = tokio::join!(task(2), task(0), task(1));
+        assert_eq!([a, b, c], [Err((2, 2)), Err((0, 0)), Err((1, 1))]);
+        assert_eq!(counter.into_inner(), 3);
+    }
+    #[tokio::test]
+    async fn map_any_sync_or_async() {
+        assert_eq!(Result::<u8, u8>::Ok(1).map_any_fut(|x: u8| x + 1).await, Ok(2));
+        assert_eq!(Result::<u8, u8>::Ok(1).map_any_fut::<u8, _, AsyncClosure>(async |x: u8| x + 1).await, Ok(2));
+        let mapped: Result<u8, u8> = Result::<u8, u8>::Ok(1).map_any_fut(async |x: u8| x + 1).await;
+        assert_eq!(mapped, Ok(2));
+    }
+    #[tokio::test]
+    async fn map_any_on_err() {
+        assert_eq!(Result::<u8, u8>::Err(1).map_any_fut(|x: u8| x + 1).await, Err(1));
+    }
+    #[tokio::test]
+    async fn map_or_context_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).map_fut_or_context(|x| *x, async |x| Ok::<u16, u8>(x as u16 + 1)).await, 
+            Ok(2u16)
+        );
+    }
+    #[tokio::test]
+    async fn map_or_context_on_map_err() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).map_fut_or_context(|x| *x, async |x| Err::<u16, u8>(x + 1)).await, 
+            Err((Some(1u8), 2u8))
+        );
+    }
+    #[tokio::test]
+    async fn map_or_context_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1).map_fut_or_context(|x| *x, async |x| Ok::<u16, u8>(x as u16)).await, 
+            Err((None, 1u8))
+        );
+    }
+    #[tokio::test]
+    async fn map_with_priority_on_ok() {
+        let spawner = spawn::tests::CountingSpawner::default();
+        assert_eq!(
+            Result::<u8, u8>::Ok(1).map_fut_with_priority(&spawner, Priority::High, async |x| x + 1).await, 
+            Ok(2u8)
         );
+        assert_eq!(spawner.spawned.get(), 1);
     }
 }
@@ -0,0 +1,129 @@
+/// An abstraction of a concurrency limiter such as a semaphore.
+/// 
+/// The crate doesn't depend on any runtime. Combinators that bound their concurrency, such as
+/// [ExtraResultVec::map_all_limited_fut](crate::ExtraResultVec::map_all_limited_fut), take a [Limiter].
+/// 
+/// The contract is that `acquire` resolves to a permit once the work is allowed to run
+/// and the permit is held until the work is completed. Dropping the permit release it.
+pub trait Limiter {
+    /// A guard that keep a slot of the limiter until it is dropped.
+    type Permit;
+
+    /// Wait until a slot is available and return a permit for it.
+    fn acquire(&self) -> impl Future<Output = Self::Permit>;
+}
+
+impl<L> Limiter for &L
+where
+    L: Limiter + ?Sized,
+{
+    type Permit = L::Permit;
+
+    #[inline]
+    fn acquire(&self) -> impl Future<Output = Self::Permit> {
+        (**self).acquire()
+    }
+}
+
+#[cfg(feature = "std")]
+pub use counting::*;
+
+#[cfg(feature = "std")]
+mod counting {
+    use super::Limiter;
+    use core::{future::poll_fn, task::{Poll, Waker}};
+    use std::{sync::{Arc, Mutex}, vec::Vec};
+
+    /// A runtime agnostic counting semaphore that implements [Limiter].
+    /// 
+    /// It allows up to the given number of permits at once. Cloning it share the same permits.
+    /// Every waiting task is woken up when a permit is released so it is meant for small number of waiters,
+    /// or as an example of implementing [Limiter]. Prefer an adapter to the semaphore of the runtime in use.
+    /// This type is only available with `std` feature.
+    #[derive(Clone, Debug)]
+    pub struct CountingLimiter {
+        state: Arc<Mutex<State>>,
+    }
+
+    #[derive(Debug)]
+    struct State {
+        available: usize,
+        waiters: Vec<Waker>,
+    }
+
+    /// A permit of [CountingLimiter]. The permit is released when it is dropped.
+    #[derive(Debug)]
+    pub struct CountingPermit {
+        state: Arc<Mutex<State>>,
+    }
+
+    impl CountingLimiter {
+        /// Create a limiter that allow up to `permits` permits at once.
+        pub fn new(permits: usize) -> Self {
+            CountingLimiter { state: Arc::new(Mutex::new(State { available: permits, waiters: Vec::new() })) }
+        }
+        /// Return the number of permits that can be acquired without waiting.
+        pub fn available(&self) -> usize {
+            self.state.lock().unwrap_or_else(|e| e.into_inner()).available
+        }
+    }
+
+    impl Limiter for CountingLimiter {
+        type Permit = CountingPermit;
+
+        fn acquire(&self) -> impl Future<Output = Self::Permit> {
+            poll_fn(|cx| {
+                let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                if state.available > 0 {
+                    state.available -= 1;
+                    Poll::Ready(CountingPermit { state: self.state.clone() })
+                } else {
+                    state.waiters.push(cx.waker().clone());
+                    Poll::Pending
+                }
+            })
+        }
+    }
+
+    impl Drop for CountingPermit {
+        fn drop(&mut self) {
+            let waiters = {
+                let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+                state.available += 1;
+                core::mem::take(&mut state.waiters)
+            };
+            waiters.into_iter().for_each(Waker::wake);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::time::{Sleeper, tests::YieldSleeper};
+
+    #[tokio::test]
+    async fn counting_limiter_release_permit() {
+        let limiter = CountingLimiter::new(1);
+        let permit = limiter.acquire().await;
+        assert_eq!(limiter.available(), 0);
+        drop(permit);
+        assert_eq!(limiter.available(), 1);
+    }
+    #[tokio::test]
+    async fn counting_limiter_wait_for_permit() {
+        let limiter = CountingLimiter::new(1);
+        let permit = limiter.acquire().await;
+        let waiting = async {
+            let _permit = limiter.acquire().await;
+            2u8
+        };
+        let releasing = async {
+            YieldSleeper.sleep(0).await;
+            drop(permit);
+            1u8
+        };
+        assert_eq!(tokio::join!(waiting, releasing), (2u8, 1u8));
+        assert_eq!(limiter.available(), 1);
+    }
+}
@@ -0,0 +1,82 @@
+/// Await a [Future] of [Result] and return early with the error, like `?` but for futures.
+/// 
+/// It must be used inside an `async` function, block or closure that return [Result].
+/// It awaits the expression and evaluates to the value if it is Ok.
+/// If it is Err, the error is converted with [From] into the error type of the enclosing function and returned.
+/// 
+/// `try_fut!(fut)` is the same as `fut.await?` but it gives the type inference a hint that the expression is a future of [Result].
+/// It helps when the `?` can't infer the error type of an async closure or a chain of combinators.
+/// Note that the [try_fut](fn@crate::try_fut) function is a different thing. It starts a pipeline from an async function.
+/// 
+/// Supported expressions are anything that implement [IntoFuture] with [Result] as output, e.g.
+/// - a call to an async function, `try_fut!(fetch(id))`,
+/// - a method of [ExtraResult](crate::ExtraResult), `try_fut!(res.map_fut(f))`,
+/// - a pipeline, `try_fut!(res.into_pipeline().map_fut(f).and_then_fut(g))`,
+/// - an `async` block, `try_fut!(async { ... })`.
+/// 
+/// A plain [Result] is not a future. Use `?` for it.
+/// Inside an `async` closure, the error is returned from the closure, not from the function that define it.
+/// 
+/// # Example
+/// ```
+/// use extra_result::*;
+/// 
+/// #[derive(Debug, PartialEq)]
+/// struct AppError(u8);
+/// 
+/// impl From<u8> for AppError {
+///     fn from(code: u8) -> Self {
+///         AppError(code)
+///     }
+/// }
+/// 
+/// async fn load(id: u8) -> Result<u8, u8> {
+///     if id > 0 { Ok(id) } else { Err(id) }
+/// }
+/// 
+/// async fn total(a: u8, b: u8) -> Result<u8, AppError> {
+///     let a = try_fut!(load(a));
+///     let b = try_fut!(Ok::<u8, u8>(b).into_pipeline().and_then_fut(load).map_fut(async |x| x * 2));
+///     Ok(a + b)
+/// }
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// assert_eq!(total(1, 2).await, Ok(5));
+/// assert_eq!(total(1, 0).await, Err(AppError(0)));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_fut {
+    ($fut:expr $(,)?) => {
+        match ::core::future::IntoFuture::into_future($fut).await {
+            ::core::result::Result::Ok(v) => v,
+            ::core::result::Result::Err(e) => return ::core::result::Result::Err(::core::convert::From::from(e)),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ExtraResult;
+
+    #[tokio::test]
+    async fn try_fut_on_ok() {
+        let res = async || -> Result<u8, u8> {
+            let x = try_fut!(Ok::<u8, u8>(1).map_fut(async |x| x + 1));
+            Ok(x + 1)
+        };
+        assert_eq!(res().await, Ok(3));
+    }
+    #[tokio::test]
+    async fn try_fut_convert_err() {
+        let mut reached = false;
+        let res: Result<u8, u16> = async {
+            let x = try_fut!(async { Err::<u8, u8>(1) });
+            reached = true;
+            Ok(x)
+        }.await;
+        assert_eq!(res, Err(1u16));
+        assert!(!reached);
+    }
+}
@@ -0,0 +1,53 @@
+/// A marker for [MaybeAsync] implemented by a sync closure, i.e. [FnOnce].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SyncClosure;
+
+/// A marker for [MaybeAsync] implemented by an async closure, i.e. [AsyncFnOnce].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncClosure;
+
+mod sealed {
+    pub trait Sealed<T, U, M> {}
+}
+
+/// A function that take `T` and return `U`, either sync or async.
+/// 
+/// It is implemented for every [FnOnce] with [SyncClosure] marker and every [AsyncFnOnce] with [AsyncClosure] marker.
+/// The marker `M` is inferred so a method like [ExtraResult::map_any_fut](crate::ExtraResult::map_any_fut) accepts both kinds of closure.
+/// The trait is sealed so it can't be implemented outside of this crate.
+/// 
+/// The sync closure is called when the returned future is polled for the first time and its output is wrapped in a
+/// [Ready](core::future::Ready) future. There is no state machine nor allocation involved.
+/// 
+/// An async closure is also a sync closure that return a future, so both markers are possible when `U` is unknown.
+/// In that case, the compiler asks for a type annotation.
+/// Annotate the output, or the marker, e.g. `map_any_fut::<_, _, AsyncClosure>(...)`.
+/// The argument of the closure may also need an annotation because the compiler can't infer it through this trait.
+pub trait MaybeAsync<T, U, M>: sealed::Sealed<T, U, M> {
+    /// Call the function with `arg` and return a future of its output.
+    fn call_fut(self, arg: T) -> impl Future<Output = U>;
+}
+
+impl<T, U, F> sealed::Sealed<T, U, SyncClosure> for F where F: FnOnce(T) -> U {}
+
+impl<T, U, F> MaybeAsync<T, U, SyncClosure> for F
+where
+    F: FnOnce(T) -> U,
+{
+    #[inline]
+    fn call_fut(self, arg: T) -> impl Future<Output = U> {
+        core::future::ready(self(arg))
+    }
+}
+
+impl<T, U, F> sealed::Sealed<T, U, AsyncClosure> for F where F: AsyncFnOnce(T) -> U {}
+
+impl<T, U, F> MaybeAsync<T, U, AsyncClosure> for F
+where
+    F: AsyncFnOnce(T) -> U,
+{
+    #[inline]
+    fn call_fut(self, arg: T) -> impl Future<Output = U> {
+        self(arg)
+    }
+}
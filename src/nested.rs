@@ -0,0 +1,337 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::join::join_all;
+
+/// Add extra functionalities to a [Result] that holds a [Future] as its value.
+pub trait ExtraResultFuture<Fut, E>
+where
+    Fut: Future,
+{
+    /// Await the future inside the [Result] if it is Ok.
+    /// 
+    /// It awaits the future inside the [Result] and wraps its output in Ok.
+    /// If the [Result] is Err, it return the same error as original.
+    /// 
+    /// It turns a `Result<impl Future<Output = U>, E>` into a `impl Future<Output = Result<U, E>>`.
+    fn sequence_fut(self) -> impl Future<Output = Result<Fut::Output, E>>;
+}
+
+impl<Fut, E> ExtraResultFuture<Fut, E> for Result<Fut, E>
+where
+    Fut: Future,
+{
+    /// Turn a [Result] of [Future] into a [Future] of [Result].
+    #[inline]
+    fn sequence_fut(self) -> impl Future<Output = Result<Fut::Output, E>> {
+        async {
+            match self {
+                Ok(fut) => Ok(fut.await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+/// Add extra functionalities to a [Result] that holds a [Vec] of [Future] as its value.
+/// 
+/// This trait is only available with `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait ExtraResultFutures<Fut, E>
+where
+    Fut: Future,
+{
+    /// Await all the futures inside the [Result] concurrently if it is Ok.
+    /// 
+    /// It awaits all the futures inside the [Result] and wraps their outputs in Ok.
+    /// The outputs are in the same order as the futures.
+    /// If the [Result] is Err, it return the same error as original.
+    fn await_all_fut(self) -> impl Future<Output = Result<Vec<Fut::Output>, E>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<Fut, E> ExtraResultFutures<Fut, E> for Result<Vec<Fut>, E>
+where
+    Fut: Future,
+{
+    /// Turn a [Result] of [Vec] of [Future] into a [Future] of [Result] of [Vec].
+    #[inline]
+    fn await_all_fut(self) -> impl Future<Output = Result<Vec<Fut::Output>, E>> {
+        async {
+            match self {
+                Ok(futs) => Ok(join_all(futs).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+/// Add extra functionalities to a [Result] that holds a [Future] in both Ok and Err.
+pub trait ExtraResultBiFuture<FutT, FutE>
+where
+    FutT: Future,
+    FutE: Future,
+{
+    /// Await the future inside the [Result], whichever arm it is.
+    /// 
+    /// It awaits the future inside the [Result] if it is Ok and wraps its output in Ok.
+    /// It awaits the future inside the [Result] if it is Err and wraps its output in Err.
+    /// 
+    /// It turns a `Result<impl Future<Output = T>, impl Future<Output = E>>` into a `impl Future<Output = Result<T, E>>`.
+    fn resolve_fut(self) -> impl Future<Output = Result<FutT::Output, FutE::Output>>;
+}
+
+impl<FutT, FutE> ExtraResultBiFuture<FutT, FutE> for Result<FutT, FutE>
+where
+    FutT: Future,
+    FutE: Future,
+{
+    /// Turn a [Result] of [Future] in both arms into a [Future] of [Result].
+    #[inline]
+    fn resolve_fut(self) -> impl Future<Output = Result<FutT::Output, FutE::Output>> {
+        async {
+            match self {
+                Ok(fut) => Ok(fut.await),
+                Err(fut) => Err(fut.await),
+            }
+        }
+    }
+}
+
+/// Add extra functionalities to a [Result] that holds an async function as its value.
+pub trait ExtraResultFn<F, E> {
+    /// Call the async function inside the [Result] with the given argument if it is Ok.
+    /// 
+    /// It awaits the async function inside the [Result] with `arg` and wraps its output in Ok.
+    /// If the [Result] is Err, it return the same error as original and `arg` is dropped.
+    /// 
+    /// It is the applicative "apply" for [Result], e.g. to resolve a handler first and invoke it later.
+    fn apply_fut<A, U>(self, arg: A) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(A) -> U;
+}
+
+impl<F, E> ExtraResultFn<F, E> for Result<F, E> {
+    /// Call the async function of a [Result] with an argument.
+    #[inline]
+    fn apply_fut<A, U>(self, arg: A) -> impl Future<Output = Result<U, E>>
+    where
+        F: AsyncFnOnce(A) -> U,
+    {
+        async {
+            match self {
+                Ok(f) => Ok(f(arg).await),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+/// Add extra functionalities to a [Result] that holds a reference as its value.
+pub trait ExtraResultRef<T, E> {
+    /// Same as [Result::cloned] but return a [Future].
+    /// 
+    /// It clones the referenced value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, it return the same error as original.
+    /// The clone happens synchronously when this method is called, the returned future is ready immediately.
+    fn cloned_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        T: Clone;
+    /// Same as [Result::copied] but return a [Future].
+    /// 
+    /// It copies the referenced value inside the [Result] if it is Ok.
+    /// If the [Result] is Err, it return the same error as original.
+    /// The copy happens synchronously when this method is called, the returned future is ready immediately.
+    fn copied_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        T: Copy;
+}
+
+impl<T, E> ExtraResultRef<T, E> for Result<&T, E> {
+    /// Clone the referenced value of a [Result] into a ready [Future].
+    #[inline]
+    fn cloned_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        T: Clone,
+    {
+        core::future::ready(self.cloned())
+    }
+    /// Copy the referenced value of a [Result] into a ready [Future].
+    #[inline]
+    fn copied_fut(self) -> impl Future<Output = Result<T, E>>
+    where
+        T: Copy,
+    {
+        core::future::ready(self.copied())
+    }
+}
+
+/// Add extra functionalities to a [Result] that holds a pair as its value.
+pub trait ExtraResultPair<A, B, E> {
+    /// Split the [Result] of a pair into a pair of [Result].
+    /// 
+    /// If the [Result] is Ok, it return Ok with each half of the pair.
+    /// If the [Result] is Err, it return the error in both of them. The error is cloned once so `E` must be [Clone].
+    /// The returned future is ready immediately.
+    /// 
+    /// It is useful to route each half through its own pipeline.
+    fn unzip_fut(self) -> impl Future<Output = (Result<A, E>, Result<B, E>)>
+    where
+        E: Clone;
+}
+
+impl<A, B, E> ExtraResultPair<A, B, E> for Result<(A, B), E> {
+    /// Split a [Result] of a pair into a ready [Future] of a pair of [Result].
+    #[inline]
+    fn unzip_fut(self) -> impl Future<Output = (Result<A, E>, Result<B, E>)>
+    where
+        E: Clone,
+    {
+        core::future::ready(match self {
+            Ok((a, b)) => (Ok(a), Ok(b)),
+            Err(e) => (Err(e.clone()), Err(e)),
+        })
+    }
+}
+
+/// Add extra functionalities to an [Option] of [Result], e.g. the outcome of a fallible optional lookup.
+pub trait ExtraOptionResult<T, E> {
+    /// Same as [ExtraResult::map_fut](crate::ExtraResult::map_fut) but apply to the [Result] inside the [Option].
+    /// 
+    /// It calls the async function with the value if it is `Some(Ok)` and wraps its output in `Some(Ok)`.
+    /// If it is `Some(Err)` or `None`, it return the same value as original without calling the function.
+    fn map_inner_fut<U, F>(self, f: F) -> impl Future<Output = Option<Result<U, E>>>
+    where
+        F: AsyncFnOnce(T) -> U;
+    /// Same as [Option::transpose] but return a [Future].
+    /// 
+    /// It converts `None` into `Ok(None)`, `Some(Ok(v))` into `Ok(Some(v))` and `Some(Err(e))` into `Err(e)`.
+    /// The returned future is ready immediately.
+    fn transpose_fut(self) -> impl Future<Output = Result<Option<T>, E>>;
+}
+
+impl<T, E> ExtraOptionResult<T, E> for Option<Result<T, E>> {
+    /// Convert an [Option] of [Result] into another one with async mapping function.
+    #[inline]
+    fn map_inner_fut<U, F>(self, f: F) -> impl Future<Output = Option<Result<U, E>>>
+    where
+        F: AsyncFnOnce(T) -> U,
+    {
+        async {
+            match self {
+                Some(Ok(v)) => Some(Ok(f(v).await)),
+                Some(Err(e)) => Some(Err(e)),
+                None => None,
+            }
+        }
+    }
+    /// Transpose an [Option] of [Result] into a ready [Future].
+    #[inline]
+    fn transpose_fut(self) -> impl Future<Output = Result<Option<T>, E>> {
+        core::future::ready(self.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn sequence_inline_on_ok() {
+        let res: Result<_, u8> = Ok(async { 1u8 });
+        assert_eq!(res.sequence_fut().await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn sequence_inline_on_err() {
+        let res: Result<core::future::Ready<u8>, u8> = Err(1u8);
+        assert_eq!(res.sequence_fut().await, Err(1u8));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn sequence_boxed_on_ok() {
+        use crate::BoxedFuture;
+        use alloc::boxed::Box;
+
+        let res: Result<BoxedFuture<'_, u8>, u8> = Ok(Box::pin(async { 1u8 }));
+        assert_eq!(res.sequence_fut().await, Ok(1u8));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn await_all_on_ok() {
+        let futs = (0..3u8).map(|i| async move {
+            if i == 0 {
+                tokio::task::yield_now().await;
+            }
+            i
+        });
+        let res: Result<Vec<_>, u8> = Ok(futs.collect());
+        assert_eq!(res.await_all_fut().await, Ok(Vec::from([0u8, 1, 2])));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn await_all_on_err() {
+        let res: Result<Vec<core::future::Ready<u8>>, u8> = Err(1);
+        assert_eq!(res.await_all_fut().await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn apply_on_ok() {
+        let res: Result<_, u8> = Ok(async |x: u8| x + 1);
+        assert_eq!(res.apply_fut(1u8).await, Ok(2u8));
+    }
+    #[tokio::test]
+    async fn apply_on_err() {
+        let res: Result<fn(u8) -> core::future::Ready<u8>, u8> = Err(1u8);
+        assert_eq!(res.apply_fut(1u8).await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn cloned_on_ok() {
+        let value = [1u8, 2];
+        assert_eq!(Result::<&[u8; 2], u8>::Ok(&value).cloned_fut().await, Ok([1u8, 2]));
+    }
+    #[tokio::test]
+    async fn cloned_on_err() {
+        assert_eq!(Result::<&[u8; 2], u8>::Err(1).cloned_fut().await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn copied_on_ok() {
+        assert_eq!(Result::<&u8, u8>::Ok(&1).copied_fut().await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn copied_on_err() {
+        assert_eq!(Result::<&u8, u8>::Err(1).copied_fut().await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn resolve_on_ok() {
+        let res: Result<_, core::future::Ready<&str>> = Ok(async { 1u8 });
+        assert_eq!(res.resolve_fut().await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn resolve_on_err() {
+        let res: Result<core::future::Pending<u8>, _> = Err(async { "failed" });
+        assert_eq!(res.resolve_fut().await, Err("failed"));
+    }
+    #[tokio::test]
+    async fn map_inner_on_some_ok() {
+        assert_eq!(Some(Ok::<u8, u8>(1)).map_inner_fut(async |x| x + 1).await, Some(Ok(2u8)));
+    }
+    #[tokio::test]
+    async fn map_inner_pass_through() {
+        assert_eq!(Some(Err::<u8, u8>(1)).map_inner_fut(async |x| x + 1).await, Some(Err(1u8)));
+        assert_eq!(None::<Result<u8, u8>>.map_inner_fut(async |x| x + 1).await, None);
+    }
+    #[tokio::test]
+    async fn transpose_option_result() {
+        assert_eq!(Some(Ok::<u8, u8>(1)).transpose_fut().await, Ok(Some(1u8)));
+        assert_eq!(Some(Err::<u8, u8>(1)).transpose_fut().await, Err(1u8));
+        assert_eq!(None::<Result<u8, u8>>.transpose_fut().await, Ok(None));
+    }
+    #[tokio::test]
+    async fn unzip_on_ok() {
+        assert_eq!(Result::<(u8, u16), u8>::Ok((1, 2)).unzip_fut().await, (Ok(1u8), Ok(2u16)));
+    }
+    #[tokio::test]
+    async fn unzip_on_err() {
+        assert_eq!(Result::<(u8, u16), u8>::Err(1).unzip_fut().await, (Err(1u8), Err(1u8)));
+    }
+}
@@ -0,0 +1,102 @@
+use core::{any::Any, fmt, future::poll_fn, pin::pin, task::Poll};
+use std::{
+    boxed::Box,
+    panic::{AssertUnwindSafe, catch_unwind},
+    string::String,
+};
+
+/// An error returned when an async function panicked.
+/// 
+/// It captures the panic message if the panic payload is a string,
+/// which is the case for `panic!` with a message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panicked {
+    message: Option<String>,
+}
+
+impl Panicked {
+    pub(crate) fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(s) => Some(*s),
+            Err(payload) => payload.downcast_ref::<&'static str>().map(|s| String::from(*s)),
+        };
+        Panicked { message }
+    }
+    /// Return the panic message if it is available.
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for Panicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "async function panicked: {message}"),
+            None => f.write_str("async function panicked"),
+        }
+    }
+}
+
+impl core::error::Error for Panicked {}
+
+/// Drive the future to completion and convert a panic while polling it into Err.
+pub(crate) fn catch_unwind_fut<Fut>(fut: Fut) -> impl Future<Output = Result<Fut::Output, Panicked>>
+where
+    Fut: Future,
+{
+    async move {
+        let mut fut = pin!(fut);
+        poll_fn(move |cx| match catch_unwind(AssertUnwindSafe(|| fut.as_mut().poll(cx))) {
+            Ok(Poll::Ready(v)) => Poll::Ready(Ok(v)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(Panicked::from_payload(payload))),
+        }).await
+    }
+}
+
+/// Run the async function and convert a panic into [Panicked] error.
+/// 
+/// It returns Ok with the output of the function if it completed normally.
+/// If the function panicked, either when it was called or when its future was polled,
+/// it returns Err with [Panicked] and the future is dropped.
+/// 
+/// This only works when panic strategy is `unwind`. With `panic = "abort"`, the process is aborted as usual.
+/// This function is only available with `std` feature.
+pub fn catch_fut<T, F>(f: F) -> impl Future<Output = Result<T, Panicked>>
+where
+    F: AsyncFnOnce() -> T,
+{
+    async move {
+        match catch_unwind(AssertUnwindSafe(|| f())) {
+            Ok(fut) => catch_unwind_fut(fut).await,
+            Err(payload) => Err(Panicked::from_payload(payload)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::format;
+
+    #[tokio::test]
+    async fn catch_on_complete() {
+        assert_eq!(catch_fut(async || { 1u8 }).await, Ok(1u8));
+    }
+    #[tokio::test]
+    async fn catch_on_panic() {
+        let err = catch_fut(async || -> u8 {
+            tokio::task::yield_now().await;
+            panic!("boom")
+        }).await.unwrap_err();
+        assert_eq!(err.message(), Some("boom"));
+    }
+    #[tokio::test]
+    async fn catch_on_formatted_panic() {
+        let code = 42;
+        let err = catch_fut(async || -> u8 { panic!("boom {code}") }).await.unwrap_err();
+        assert_eq!(err.message(), Some("boom 42"));
+        assert_eq!(format!("{err}"), "async function panicked: boom 42");
+    }
+}
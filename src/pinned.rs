@@ -0,0 +1,108 @@
+use core::{pin::Pin, task::{Context, Poll}};
+
+/// Add poll based functionalities to a pinned [Result] that holds a [Future] as its value.
+/// 
+/// It is a low level building block for a hand-written [Future] that store a `Result<Fut, E>`
+/// and want to drive the inner future without `Box::pin`, even if `Fut` or `E` is `!Unpin`.
+/// 
+/// # Pin projection
+/// Both the Ok and the Err payload are structurally pinned, the same way as [Option::as_pin_mut].
+/// Once a `Result<Fut, E>` is pinned, the payload is never moved out of it and the variant is never changed
+/// until it is dropped. Every method here only hand out pinned references to the payload so the invariant hold.
+/// A hand-written [Future] that embed the [Result] must also project it as a structurally pinned field.
+pub trait PinnedExtraResult<Fut, E>
+where
+    Fut: Future,
+{
+    /// Convert a pinned reference of the [Result] into a [Result] of pinned references.
+    fn as_pin_mut(self: Pin<&mut Self>) -> Result<Pin<&mut Fut>, Pin<&mut E>>;
+    /// Poll the future inside the [Result] and map its output with a sync function once it is ready.
+    /// 
+    /// If the [Result] is Ok, it polls the inner future with the given [Context].
+    /// When the inner future is ready, it calls the function with the output and return `Poll::Ready(Ok(u))`.
+    /// If the inner future is pending, the function is dropped without being called and it return [Poll::Pending].
+    /// If the [Result] is Err, it return `Poll::Ready(Err(e))` with a pinned reference to the error immediately.
+    /// 
+    /// Like any [Future], the inner future must not be polled again after it is ready.
+    /// 
+    /// # Example
+    /// ```
+    /// use core::{future::poll_fn, pin::pin};
+    /// use extra_result::*;
+    /// 
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let mut result = pin!(Ok::<_, u8>(async { 1u8 }));
+    /// let mapped = poll_fn(|cx| result.as_mut().poll_map_fut(cx, |v| v + 1).map(|r| r.map_err(|e| *e))).await;
+    /// assert_eq!(mapped, Ok(2));
+    /// # }
+    /// ```
+    fn poll_map_fut<U, F>(self: Pin<&mut Self>, cx: &mut Context<'_>, f: F) -> Poll<Result<U, Pin<&mut E>>>
+    where
+        F: FnOnce(Fut::Output) -> U;
+}
+
+impl<Fut, E> PinnedExtraResult<Fut, E> for Result<Fut, E>
+where
+    Fut: Future,
+{
+    /// Project a pinned [Result] into its pinned payload.
+    #[inline]
+    fn as_pin_mut(self: Pin<&mut Self>) -> Result<Pin<&mut Fut>, Pin<&mut E>> {
+        // SAFETY: The payload is structurally pinned. It is never moved out of the pinned [Result]
+        // and the returned references keep it pinned.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Ok(fut) => Ok(Pin::new_unchecked(fut)),
+                Err(e) => Err(Pin::new_unchecked(e)),
+            }
+        }
+    }
+    /// Poll a pinned [Result] of [Future] and map its output.
+    #[inline]
+    fn poll_map_fut<U, F>(self: Pin<&mut Self>, cx: &mut Context<'_>, f: F) -> Poll<Result<U, Pin<&mut E>>>
+    where
+        F: FnOnce(Fut::Output) -> U,
+    {
+        match self.as_pin_mut() {
+            Ok(fut) => fut.poll(cx).map(|v| Ok(f(v))),
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{future::poll_fn, marker::PhantomPinned, pin::pin};
+    use crate::time::{Sleeper, tests::YieldSleeper};
+
+    #[tokio::test]
+    async fn poll_map_on_ok() {
+        let mut result = pin!(Result::<_, u8>::Ok(async {
+            YieldSleeper.sleep(0).await;
+            1u8
+        }));
+        let mut polls = 0;
+        let mapped = poll_fn(|cx| {
+            polls += 1;
+            result.as_mut().poll_map_fut(cx, |v| v + 1).map(|r| r.map_err(|e| *e))
+        }).await;
+        assert_eq!(mapped, Ok(2u8));
+        assert_eq!(polls, 2);
+    }
+    #[tokio::test]
+    async fn poll_map_on_err() {
+        let mut result = pin!(Result::<core::future::Ready<u8>, _>::Err((1u8, PhantomPinned)));
+        let mapped = poll_fn(|cx| {
+            result.as_mut().poll_map_fut(cx, |_| -> u8 { unreachable!() }).map(|r| r.map_err(|e| e.0))
+        }).await;
+        assert_eq!(mapped, Err(1u8));
+    }
+    #[test]
+    fn as_pin_mut_on_ok() {
+        let mut result = pin!(Result::<_, u8>::Ok(core::future::ready(1u8)));
+        assert!(result.as_mut().as_pin_mut().is_ok());
+        assert!(result.as_ref().is_ok());
+    }
+}
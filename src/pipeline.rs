@@ -0,0 +1,277 @@
+use core::{fmt, future::Ready, marker::PhantomData, pin::Pin, task::{Context, Poll}};
+
+use crate::ExtraResult;
+
+/// The state of a [ResultFuture] returned by [ResultFuture::poll_state].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ResultState<T, E> {
+    /// The future is not completed yet. The waker of the given context will be woken up once it can make progress.
+    Pending,
+    /// The future is completed with the [Result].
+    Ready(Result<T, E>),
+}
+
+impl<T, E> ResultState<T, E> {
+    /// Return true if the future is not completed yet.
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ResultState::Pending)
+    }
+    /// Return true if the future is completed.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        matches!(self, ResultState::Ready(_))
+    }
+}
+
+impl<T, E> From<Poll<Result<T, E>>> for ResultState<T, E> {
+    #[inline]
+    fn from(poll: Poll<Result<T, E>>) -> Self {
+        match poll {
+            Poll::Ready(result) => ResultState::Ready(result),
+            Poll::Pending => ResultState::Pending,
+        }
+    }
+}
+
+/// A [Future] of [Result] that can be chained with async combinators without awaiting each step.
+/// 
+/// It is created by [ExtraResult::into_pipeline] or [ResultFuture::new].
+/// Each combinator wraps the current future into a new [ResultFuture] so nothing run until the final future is awaited.
+/// For example, `res.into_pipeline().map_fut(a).and_then_fut(b).await` awaits `a` and `b` in order.
+/// 
+/// The `Fut` parameter is the future being wrapped. It is a ready future when created by [ExtraResult::into_pipeline].
+/// 
+/// Its [Debug](fmt::Debug) output show whether the wrapped future is still pending or already completed,
+/// without requiring `T`, `E` or `Fut` to be [Debug](fmt::Debug), e.g. `ResultFuture { state: Pending }`.
+/// 
+/// # Panics
+/// Polling it again after it returned [Poll::Ready] panics with "combinator future polled after completion"
+/// instead of polling the wrapped future which may misbehave.
+/// With `futures` feature, it implements `FusedFuture` so `futures::select!` can skip it once completed.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ResultFuture<T, E, Fut = Ready<Result<T, E>>> {
+    fut: Fut,
+    done: bool,
+    _marker: PhantomData<fn() -> Result<T, E>>,
+}
+
+impl<T, E, Fut> ResultFuture<T, E, Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// Wrap a [Future] of [Result] into a [ResultFuture].
+    #[inline]
+    pub fn new(fut: Fut) -> Self {
+        ResultFuture { fut, done: false, _marker: PhantomData }
+    }
+    /// Poll the pipeline once and return its state, for a hand-written executor or reactor.
+    /// 
+    /// It is the same as [Future::poll] but the outcome is a [ResultState].
+    /// It returns [ResultState::Pending] if any step of the pipeline is still waiting.
+    /// In that case, the waker of `cx` is woken up once the pipeline can make progress and it should be polled again.
+    /// It returns [ResultState::Ready] once, with the final [Result]. It panics if it is polled again afterward.
+    /// 
+    /// Use [core::pin::pin] or `Box::pin` to get a pinned reference of the pipeline.
+    #[inline]
+    pub fn poll_state(self: Pin<&mut Self>, cx: &mut Context<'_>) -> ResultState<T, E> {
+        self.poll(cx).into()
+    }
+    /// Pipeline version of [ExtraResult::map_fut].
+    #[inline]
+    pub fn map_fut<U, F>(self, f: F) -> ResultFuture<U, E, impl Future<Output = Result<U, E>>>
+    where
+        F: AsyncFnOnce(T) -> U,
+    {
+        ResultFuture::new(async move { self.await.map_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::map_err_fut].
+    #[inline]
+    pub fn map_err_fut<F, U>(self, f: F) -> ResultFuture<T, U, impl Future<Output = Result<T, U>>>
+    where
+        F: AsyncFnOnce(E) -> U,
+    {
+        ResultFuture::new(async move { self.await.map_err_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::inspect_fut].
+    #[inline]
+    pub fn inspect_fut<F>(self, f: F) -> ResultFuture<T, E, impl Future<Output = Result<T, E>>>
+    where
+        F: AsyncFnOnce(&T),
+    {
+        ResultFuture::new(async move { self.await.inspect_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::inspect_err_fut].
+    #[inline]
+    pub fn inspect_err_fut<F>(self, f: F) -> ResultFuture<T, E, impl Future<Output = Result<T, E>>>
+    where
+        F: AsyncFnOnce(&E),
+    {
+        ResultFuture::new(async move { self.await.inspect_err_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::and_then_fut].
+    #[inline]
+    pub fn and_then_fut<U, F>(self, f: F) -> ResultFuture<U, E, impl Future<Output = Result<U, E>>>
+    where
+        F: AsyncFnOnce(T) -> Result<U, E>,
+    {
+        ResultFuture::new(async move { self.await.and_then_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::or_else_fut].
+    #[inline]
+    pub fn or_else_fut<U, F>(self, f: F) -> ResultFuture<T, U, impl Future<Output = Result<T, U>>>
+    where
+        F: AsyncFnOnce(E) -> Result<T, U>,
+    {
+        ResultFuture::new(async move { self.await.or_else_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::recover_fut].
+    #[inline]
+    pub fn recover_fut<F>(self, f: F) -> ResultFuture<T, E, impl Future<Output = Result<T, E>>>
+    where
+        F: AsyncFnOnce(E) -> Result<T, E>,
+    {
+        ResultFuture::new(async move { self.await.recover_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::validate_fut].
+    #[inline]
+    pub fn validate_fut<F>(self, f: F) -> ResultFuture<T, E, impl Future<Output = Result<T, E>>>
+    where
+        F: AsyncFnOnce(&T) -> Result<(), E>,
+    {
+        ResultFuture::new(async move { self.await.validate_fut(f).await })
+    }
+    /// Pipeline version of [ExtraResult::tap_fut].
+    #[inline]
+    pub fn tap_fut<F>(self, f: F) -> ResultFuture<T, E, impl Future<Output = Result<T, E>>>
+    where
+        F: AsyncFnOnce(&Result<T, E>),
+    {
+        ResultFuture::new(async move { self.await.tap_fut(f).await })
+    }
+}
+
+impl<T, E, Fut> Future for ResultFuture<T, E, Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `fut` is structurally pinned. It is never moved out of `self`
+        // and `ResultFuture` doesn't implement `Drop`. `done` is never pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        assert!(!this.done, "combinator future polled after completion");
+        let poll = unsafe { Pin::new_unchecked(&mut this.fut) }.poll(cx);
+        if poll.is_ready() {
+            this.done = true;
+        }
+        poll
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T, E, Fut> futures::future::FusedFuture for ResultFuture<T, E, Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<T, E, Fut> fmt::Debug for ResultFuture<T, E, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.done { "Completed" } else { "Pending" };
+        f.debug_struct("ResultFuture").field("state", &format_args!("{state}")).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pipeline_on_ok() {
+        assert_eq!(
+            Result::<u8, u8>::Ok(1)
+                .into_pipeline()
+                .map_fut(async |x| x + 1)
+                .and_then_fut(async |x| Ok(x * 2))
+                .map_err_fut(async |_| panic!("This should never be called"))
+                .await, 
+            Ok::<u8, u8>(4)
+        );
+    }
+    #[tokio::test]
+    async fn pipeline_on_err() {
+        assert_eq!(
+            Result::<u8, u8>::Err(1)
+                .into_pipeline()
+                .map_fut(async |_| -> u8 { panic!("This should never be called") })
+                .map_err_fut(async |x| x + 1)
+                .or_else_fut(async |x| Err::<u8, u8>(x * 2))
+                .await, 
+            Err(4u8)
+        );
+    }
+    #[tokio::test]
+    async fn pipeline_is_lazy() {
+        let mut called = false;
+        let fut = Result::<u8, u8>::Ok(1)
+            .into_pipeline()
+            .inspect_fut(async |_| { called = true; });
+        drop(fut);
+        assert!(!called);
+    }
+    #[tokio::test]
+    async fn pipeline_from_future() {
+        assert_eq!(
+            ResultFuture::new(async { Ok::<u8, u8>(1) })
+                .validate_fut(async |x| if *x > 0 { Ok(()) } else { Err(0) })
+                .await, 
+            Ok(1u8)
+        );
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn pipeline_debug_state() {
+        let mut fut = core::pin::pin!(Result::<u8, u8>::Ok(1).into_pipeline().map_fut(async |x| x + 1));
+        assert_eq!(alloc::format!("{fut:?}"), "ResultFuture { state: Pending }");
+        assert_eq!(core::future::poll_fn(|cx| fut.as_mut().poll(cx)).await, Ok(2u8));
+        assert_eq!(alloc::format!("{fut:?}"), "ResultFuture { state: Completed }");
+    }
+    #[tokio::test]
+    async fn pipeline_poll_state() {
+        use crate::time::{Sleeper, tests::YieldSleeper};
+
+        let mut fut = core::pin::pin!(
+            Result::<u8, u8>::Ok(1).into_pipeline().map_fut(async |x| { YieldSleeper.sleep(0).await; x + 1 })
+        );
+        let mut states = [ResultState::Pending; 2];
+        for state in states.iter_mut() {
+            *state = core::future::poll_fn(|cx| Poll::Ready(fut.as_mut().poll_state(cx))).await;
+        }
+        assert!(states[0].is_pending());
+        assert_eq!(states[1], ResultState::Ready(Ok(2u8)));
+    }
+    #[tokio::test]
+    #[should_panic(expected = "combinator future polled after completion")]
+    async fn pipeline_polled_after_completion() {
+        let mut fut = core::pin::pin!(Result::<u8, u8>::Ok(1).into_pipeline().map_fut(async |x| x + 1));
+        assert_eq!(fut.as_mut().await, Ok(2));
+        let _ = fut.await;
+    }
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn pipeline_is_terminated() {
+        use futures::future::FusedFuture;
+
+        let mut fut = core::pin::pin!(Result::<u8, u8>::Ok(1).into_pipeline());
+        assert!(!fut.is_terminated());
+        assert_eq!(fut.as_mut().await, Ok(1));
+        assert!(fut.is_terminated());
+    }
+}
@@ -0,0 +1,324 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
+use core::{future::poll_fn, pin::pin, task::Poll};
+
+use crate::{Either, fuse::DebugFuse};
+
+/// Poll two futures concurrently and resolve to the output of the one that completed first.
+/// 
+/// `a` is always polled before `b` so if both of them are ready on the same poll, `a` wins.
+/// The other future is dropped without being polled again.
+pub(crate) fn race<A, B>(a: A, b: B) -> impl Future<Output = Either<A::Output, B::Output>>
+where
+    A: Future,
+    B: Future,
+{
+    async move {
+        let mut a = pin!(a);
+        let mut b = pin!(b);
+        poll_fn(move |cx| {
+            if let Poll::Ready(v) = a.as_mut().poll(cx) {
+                return Poll::Ready(Either::Left(v));
+            }
+            if let Poll::Ready(v) = b.as_mut().poll(cx) {
+                return Poll::Ready(Either::Right(v));
+            }
+            Poll::Pending
+        }).await
+    }
+}
+
+/// Poll two [Result] futures concurrently and tell which of them completed first.
+/// 
+/// It resolves to [Either::Left] with the result of `a` if `a` completed first,
+/// or [Either::Right] with the result of `b` if `b` completed first.
+/// `a` is always polled before `b` so if both of them are ready on the same poll, `a` wins.
+/// 
+/// The future that did not complete is dropped when the returned future resolves.
+/// It is not returned to the caller so any progress it made is lost.
+/// This function doesn't depend on any runtime and doesn't allocate.
+pub fn select_fut<T1, E1, T2, E2, A, B>(a: A, b: B) -> impl Future<Output = Either<Result<T1, E1>, Result<T2, E2>>>
+where
+    A: Future<Output = Result<T1, E1>>,
+    B: Future<Output = Result<T2, E2>>,
+{
+    race(a, b)
+}
+
+/// Poll two [Result] futures concurrently until one of them resolves to Ok if `stop_on_ok`, or Err otherwise.
+/// 
+/// It resolves to `stop_on_ok` as soon as a result matches it, or to the opposite once both of them completed without a match.
+fn gate<T1, E1, T2, E2, A, B>(a: A, b: B, stop_on_ok: bool) -> impl Future<Output = bool>
+where
+    A: Future<Output = Result<T1, E1>>,
+    B: Future<Output = Result<T2, E2>>,
+{
+    async move {
+        let mut a = pin!(DebugFuse::new(a));
+        let mut b = pin!(DebugFuse::new(b));
+        let mut a_done = false;
+        let mut b_done = false;
+        poll_fn(move |cx| {
+            if !a_done && let Poll::Ready(result) = a.as_mut().poll(cx) {
+                if result.is_ok() == stop_on_ok {
+                    return Poll::Ready(stop_on_ok);
+                }
+                a_done = true;
+            }
+            if !b_done && let Poll::Ready(result) = b.as_mut().poll(cx) {
+                if result.is_ok() == stop_on_ok {
+                    return Poll::Ready(stop_on_ok);
+                }
+                b_done = true;
+            }
+            if a_done && b_done {
+                Poll::Ready(!stop_on_ok)
+            } else {
+                Poll::Pending
+            }
+        }).await
+    }
+}
+
+/// Poll two [Result] futures concurrently and tell whether both of them resolved to Ok.
+/// 
+/// It resolves to false as soon as one of them resolves to Err and the other future is dropped.
+/// It resolves to true once both of them resolved to Ok. The values are dropped.
+/// `a` is always polled before `b`.
+/// This function doesn't depend on any runtime and doesn't allocate.
+pub fn both_ok_fut<T1, E1, T2, E2, A, B>(a: A, b: B) -> impl Future<Output = bool>
+where
+    A: Future<Output = Result<T1, E1>>,
+    B: Future<Output = Result<T2, E2>>,
+{
+    gate(a, b, false)
+}
+
+/// Poll two [Result] futures concurrently and tell whether any of them resolved to Ok.
+/// 
+/// It resolves to true as soon as one of them resolves to Ok and the other future is dropped.
+/// It resolves to false once both of them resolved to Err. The errors are dropped.
+/// `a` is always polled before `b`.
+/// This function doesn't depend on any runtime and doesn't allocate.
+pub fn either_ok_fut<T1, E1, T2, E2, A, B>(a: A, b: B) -> impl Future<Output = bool>
+where
+    A: Future<Output = Result<T1, E1>>,
+    B: Future<Output = Result<T2, E2>>,
+{
+    gate(a, b, true)
+}
+
+/// Poll all the given futures concurrently and return the first Ok result.
+/// 
+/// All futures are polled on every wake up in the order given by the iterator.
+/// As soon as one of them resolves to Ok, the rest of the futures are dropped and the value is returned.
+/// If all of them resolve to Err, it returns all the errors in the same order as the iterator.
+/// If the iterator is empty, it resolves to Err with an empty [Vec] immediately.
+/// 
+/// The futures are boxed so they can be `!Unpin`.
+/// This function is only available with `alloc` feature.
+#[cfg(feature = "alloc")]
+pub fn race_ok_fut<T, E, I>(futs: I) -> impl Future<Output = Result<T, Vec<E>>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>>,
+{
+    async move {
+        let mut futs: Vec<Option<Pin<Box<I::Item>>>> = futs.into_iter().map(|f| Some(Box::pin(f))).collect();
+        let mut errors: Vec<Option<E>> = futs.iter().map(|_| None).collect();
+        let mut remaining = futs.len();
+        poll_fn(move |cx| {
+            for i in 0..futs.len() {
+                let Some(fut) = futs[i].as_mut() else { continue };
+                if let Poll::Ready(result) = fut.as_mut().poll(cx) {
+                    futs[i] = None;
+                    match result {
+                        Ok(v) => {
+                            futs.clear();
+                            return Poll::Ready(Ok(v));
+                        }
+                        Err(e) => {
+                            errors[i] = Some(e);
+                            remaining -= 1;
+                        }
+                    }
+                }
+            }
+            if remaining == 0 {
+                Poll::Ready(Err(errors.drain(..).flatten().collect()))
+            } else {
+                Poll::Pending
+            }
+        }).await
+    }
+}
+
+/// Await the `first` future then the `rest` one at a time and return the first Ok result.
+/// 
+/// Unlike `race_ok_fut`, the futures are awaited strictly in order, starting with `first`.
+/// The next future is pulled from the iterator only after the previous one resolved to Err,
+/// so later futures are never created nor polled once an earlier one succeeded.
+/// If all of them resolve to Err, it returns the last error.
+/// The `first` future is mandatory so there is always an error to return, even if `rest` is empty.
+pub fn first_ok_fut<T, E, F, I>(first: F, rest: I) -> impl Future<Output = Result<T, E>>
+where
+    F: Future<Output = Result<T, E>>,
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>>,
+{
+    async move {
+        let mut last = match first.await {
+            Ok(v) => return Ok(v),
+            Err(e) => e,
+        };
+        for fut in rest {
+            match fut.await {
+                Ok(v) => return Ok(v),
+                Err(e) => last = e,
+            }
+        }
+        Err(last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{cell::Cell, future::pending};
+
+    struct DropFlag<'a>(&'a Cell<bool>);
+
+    impl Drop for DropFlag<'_> {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn race_ok_first_ok() {
+        let dropped = Cell::new(false);
+        let futs = (0..3u8).map(|i| {
+            let dropped = &dropped;
+            async move {
+                match i {
+                    0 => Err(0u8),
+                    1 => {
+                        tokio::task::yield_now().await;
+                        Ok(1u8)
+                    }
+                    _ => {
+                        let _flag = DropFlag(dropped);
+                        pending().await
+                    }
+                }
+            }
+        });
+        let mut fut = pin!(race_ok_fut(futs));
+        assert_eq!(fut.as_mut().await, Ok(1u8));
+        assert!(dropped.get());
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn race_ok_all_err() {
+        let futs = (0..3u8).map(|i| async move {
+            if i == 0 {
+                tokio::task::yield_now().await;
+            }
+            Err::<u8, u8>(i)
+        });
+        assert_eq!(race_ok_fut(futs).await, Err(Vec::from([0u8, 1, 2])));
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn race_ok_empty() {
+        assert_eq!(race_ok_fut(core::iter::empty::<core::future::Ready<Result<u8, u8>>>()).await, Err(Vec::new()));
+    }
+    #[tokio::test]
+    async fn select_left_first() {
+        let dropped = Cell::new(false);
+        let flag = DropFlag(&dropped);
+        let b = async move {
+            let _flag = flag;
+            pending::<Result<u16, u16>>().await
+        };
+        let mut fut = pin!(select_fut(async { Ok::<u8, u8>(1) }, b));
+        assert_eq!(fut.as_mut().await, Either::Left(Ok(1u8)));
+        assert!(dropped.get());
+    }
+    #[tokio::test]
+    async fn select_right_first() {
+        let a = async {
+            tokio::task::yield_now().await;
+            Ok::<u8, u8>(1)
+        };
+        assert_eq!(select_fut(a, async { Err::<u16, u16>(2) }).await, Either::Right(Err(2u16)));
+    }
+    #[tokio::test]
+    async fn select_both_ready() {
+        assert_eq!(
+            select_fut(async { Err::<u8, u8>(1) }, async { Ok::<u16, u16>(2) }).await, 
+            Either::Left(Err(1u8))
+        );
+    }
+    #[tokio::test]
+    async fn both_ok_or_either_ok() {
+        let ok = || async { Ok::<u8, u8>(1) };
+        let err = || async { Err::<u8, u8>(1) };
+        assert!(both_ok_fut(ok(), ok()).await);
+        assert!(!both_ok_fut(err(), err()).await);
+        assert!(!both_ok_fut(ok(), err()).await);
+        assert!(!both_ok_fut(err(), ok()).await);
+        assert!(either_ok_fut(ok(), ok()).await);
+        assert!(!either_ok_fut(err(), err()).await);
+        assert!(either_ok_fut(ok(), err()).await);
+        assert!(either_ok_fut(err(), ok()).await);
+    }
+    #[tokio::test]
+    async fn either_ok_short_circuit() {
+        let dropped = Cell::new(false);
+        let flag = DropFlag(&dropped);
+        let b = async move {
+            let _flag = flag;
+            pending::<Result<u16, u16>>().await
+        };
+        let mut fut = pin!(either_ok_fut(async { Ok::<u8, u8>(1) }, b));
+        assert!(fut.as_mut().await);
+        assert!(dropped.get());
+    }
+    #[tokio::test]
+    async fn both_ok_short_circuit() {
+        let a = async {
+            tokio::task::yield_now().await;
+            Err::<u8, u8>(1)
+        };
+        assert!(!both_ok_fut(a, pending::<Result<u16, u16>>()).await);
+    }
+    #[tokio::test]
+    async fn first_ok_stop_at_first_ok() {
+        let created = Cell::new(0);
+        let futs = (1..3u8).map(|i| {
+            created.set(created.get() + 1);
+            async move { Ok::<u8, u8>(i) }
+        });
+        assert_eq!(first_ok_fut(async { Err(0u8) }, futs).await, Ok(1u8));
+        assert_eq!(created.get(), 1);
+    }
+    #[tokio::test]
+    async fn first_ok_first_is_ok() {
+        let futs = (1..3u8).map(|_| async { unreachable!() });
+        assert_eq!(first_ok_fut(async { Ok::<u8, u8>(0) }, futs).await, Ok(0u8));
+    }
+    #[tokio::test]
+    async fn first_ok_all_err() {
+        let futs = (1..3u8).map(|i| async move { Err::<u8, u8>(i) });
+        assert_eq!(first_ok_fut(async { Err(0u8) }, futs).await, Err(2u8));
+    }
+    #[tokio::test]
+    async fn first_ok_no_rest() {
+        let rest = core::iter::empty::<core::future::Ready<Result<u8, u8>>>();
+        assert_eq!(first_ok_fut(async { Err::<u8, u8>(1) }, rest).await, Err(1u8));
+    }
+}
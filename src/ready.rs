@@ -0,0 +1,72 @@
+use core::{pin::Pin, task::{Context, Poll}};
+
+/// A [Future] of [Result] that resolves immediately.
+/// 
+/// It is created by [ExtraResult::into_ready_named](crate::ExtraResult::into_ready_named).
+/// Unlike [ExtraResult::ready](crate::ExtraResult::ready), its type can be named so it can be stored in a field
+/// or a collection of a concrete future type, e.g. an [Either](crate::Either) of a ready result and a pending one.
+/// 
+/// It resolves to the [Result] on the first poll and it is [Unpin] regardless of `T` and `E`.
+/// 
+/// # Panics
+/// Polling it again after it returned [Poll::Ready] panics with "combinator future polled after completion".
+#[derive(Clone, Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadyResult<T, E>(Option<Result<T, E>>);
+
+impl<T, E> ReadyResult<T, E> {
+    /// Wrap a [Result] into a [ReadyResult].
+    #[inline]
+    pub fn new(result: Result<T, E>) -> Self {
+        ReadyResult(Some(result))
+    }
+    /// Take the [Result] out without polling.
+    /// 
+    /// # Panics
+    /// It panics if the future already completed.
+    #[inline]
+    pub fn into_inner(self) -> Result<T, E> {
+        self.0.expect("combinator future polled after completion")
+    }
+}
+
+impl<T, E> From<Result<T, E>> for ReadyResult<T, E> {
+    #[inline]
+    fn from(result: Result<T, E>) -> Self {
+        ReadyResult::new(result)
+    }
+}
+
+// The `Result` is never pinned, it is moved out on the first poll.
+impl<T, E> Unpin for ReadyResult<T, E> {}
+
+impl<T, E> Future for ReadyResult<T, E> {
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(self.0.take().expect("combinator future polled after completion"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::marker::PhantomPinned;
+
+    fn assert_unpin<F: Future + Unpin>(_: &F) {}
+
+    #[tokio::test]
+    async fn ready_result_unpin() {
+        let fut = ReadyResult::<PhantomPinned, u8>::new(Err(1));
+        assert_unpin(&fut);
+        assert_eq!(fut.await.unwrap_err(), 1);
+    }
+    #[tokio::test]
+    #[should_panic(expected = "combinator future polled after completion")]
+    async fn ready_result_polled_after_completion() {
+        let mut fut = ReadyResult::<u8, u8>::new(Ok(1));
+        assert_eq!((&mut fut).await, Ok(1));
+        let _ = fut.await;
+    }
+}
@@ -0,0 +1,174 @@
+use crate::Sleeper;
+
+/// Call the async function until it returns Ok, waiting `delay_ms` milliseconds between attempts.
+/// 
+/// It calls the async function up to `attempts` times and returns the first Ok.
+/// If all the attempts return Err, it returns the last error.
+/// The delay is awaited only between attempts, not after the last one.
+/// An `attempts` of 0 is treated as 1.
+pub fn retry_fut<T, E, F, S>(sleeper: S, attempts: u32, delay_ms: u64, f: F) -> impl Future<Output = Result<T, E>>
+where
+    F: AsyncFnMut() -> Result<T, E>,
+    S: Sleeper,
+{
+    async move {
+        let sleeper = &sleeper;
+        attempt_fut(attempts, f, move || sleeper.sleep(delay_ms)).await
+    }
+}
+
+/// Call the async function until it returns Ok, without waiting between attempts.
+/// 
+/// It calls the async function up to `max_attempts` times and returns the first Ok.
+/// If all the attempts return Err, it returns the last error.
+/// The async function is never called again after it returns Ok.
+/// A `max_attempts` of 0 is treated as 1.
+/// 
+/// It is the same as [retry_fut] without a [Sleeper] and a delay.
+pub fn repeat_until_ok_fut<T, E, F>(max_attempts: u32, f: F) -> impl Future<Output = Result<T, E>>
+where
+    F: AsyncFnMut() -> Result<T, E>,
+{
+    attempt_fut(max_attempts, f, || core::future::ready(()))
+}
+
+/// Call the async function until it returns Ok, waiting for a quiet window after each failure.
+/// 
+/// It calls the async function and returns the first Ok.
+/// On Err, it awaits a fresh window from `quiet` before trying again so each failure restart the window.
+/// The window of a failure is never shared with the next one.
+/// Unlike the fixed delay of [retry_fut], the window is any future, e.g. a [Sleeper] that is restarted
+/// whenever the connection flap, so the retry happens only once things are stable.
+/// 
+/// It terminates on the first Ok, or after `max_attempts` attempts with the last error.
+/// The window is not awaited after the last attempt. A `max_attempts` of 0 is treated as 1.
+pub fn debounce_fut<T, E, F, Q, S>(f: F, quiet: Q, max_attempts: u32) -> impl Future<Output = Result<T, E>>
+where
+    F: AsyncFnMut() -> Result<T, E>,
+    Q: Fn() -> S,
+    S: Future<Output = ()>,
+{
+    attempt_fut(max_attempts, f, quiet)
+}
+
+/// Call the async function up to `max_attempts` times until it returns Ok, awaiting a future from `between` after each failure.
+/// 
+/// It returns the first Ok or the last error. `between` is not called after the last attempt.
+/// A `max_attempts` of 0 is treated as 1.
+fn attempt_fut<T, E, F, B, W>(max_attempts: u32, mut f: F, mut between: B) -> impl Future<Output = Result<T, E>>
+where
+    F: AsyncFnMut() -> Result<T, E>,
+    B: FnMut() -> W,
+    W: Future<Output = ()>,
+{
+    async move {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Ok(v) => return Ok(v),
+                Err(e) if attempt >= max_attempts => return Err(e),
+                Err(_) => {
+                    attempt += 1;
+                    between().await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::tests::YieldSleeper;
+
+    #[tokio::test]
+    async fn retry_until_ok() {
+        let mut calls = 0u8;
+        assert_eq!(
+            retry_fut(YieldSleeper, 3, 10, async || { calls += 1; if calls < 2 { Err(calls) } else { Ok(calls) } }).await, 
+            Ok(2u8)
+        );
+        assert_eq!(calls, 2);
+    }
+    #[tokio::test]
+    async fn retry_exhausted() {
+        let mut calls = 0u8;
+        assert_eq!(
+            retry_fut(YieldSleeper, 3, 10, async || -> Result<u8, u8> { calls += 1; Err(calls) }).await, 
+            Err(3u8)
+        );
+    }
+    #[tokio::test]
+    async fn retry_zero_attempts() {
+        let mut calls = 0u8;
+        assert_eq!(
+            retry_fut(YieldSleeper, 0, 10, async || -> Result<u8, u8> { calls += 1; Err(calls) }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn repeat_until_ok() {
+        let mut calls = 0u8;
+        assert_eq!(
+            repeat_until_ok_fut(5, async || { calls += 1; if calls < 2 { Err(calls) } else { Ok(calls) } }).await, 
+            Ok(2u8)
+        );
+        assert_eq!(calls, 2);
+    }
+    #[tokio::test]
+    async fn repeat_exhausted() {
+        let mut calls = 0u8;
+        assert_eq!(
+            repeat_until_ok_fut(3, async || -> Result<u8, u8> { calls += 1; Err(calls) }).await, 
+            Err(3u8)
+        );
+        assert_eq!(calls, 3);
+    }
+    #[tokio::test]
+    async fn repeat_zero_attempts() {
+        let mut calls = 0u8;
+        assert_eq!(
+            repeat_until_ok_fut(0, async || -> Result<u8, u8> { calls += 1; Err(calls) }).await, 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn debounce_until_ok() {
+        use crate::Sleeper;
+        use core::cell::Cell;
+
+        let calls = Cell::new(0u8);
+        let windows = Cell::new(0u8);
+        let quiet = || {
+            assert_eq!(windows.get(), calls.get() - 1);
+            windows.set(windows.get() + 1);
+            YieldSleeper.sleep(10)
+        };
+        assert_eq!(
+            debounce_fut(async || { calls.set(calls.get() + 1); if calls.get() < 3 { Err(calls.get()) } else { Ok(calls.get()) } }, quiet, 5).await, 
+            Ok(3u8)
+        );
+        assert_eq!(windows.get(), 2);
+    }
+    #[tokio::test]
+    async fn debounce_exhausted() {
+        let windows = core::cell::Cell::new(0u8);
+        let quiet = || {
+            windows.set(windows.get() + 1);
+            core::future::ready(())
+        };
+        let mut calls = 0u8;
+        assert_eq!(
+            debounce_fut(async || -> Result<u8, u8> { calls += 1; Err(calls) }, quiet, 3).await, 
+            Err(3u8)
+        );
+        assert_eq!(windows.get(), 2);
+    }
+    #[tokio::test]
+    async fn debounce_zero_attempts() {
+        assert_eq!(
+            debounce_fut(async || Err::<u8, u8>(1), || -> core::future::Pending<()> { unreachable!() }, 0).await, 
+            Err(1u8)
+        );
+    }
+}
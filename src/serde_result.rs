@@ -0,0 +1,127 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ExtraResult;
+
+/// A newtype of [Result] that implement [Serialize] and [Deserialize].
+/// 
+/// It is encoded as an externally tagged enum, the same representation `serde` use for [Result].
+/// In JSON, `Ok(v)` is `{"Ok": v}` and `Err(e)` is `{"Err": e}`.
+/// The representation is stable so a value round-trip through any self-describing format unchanged.
+/// This type is only available with `serde` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SerdeResult<T, E>(pub Result<T, E>);
+
+impl<T, E> SerdeResult<T, E> {
+    /// Unwrap the [SerdeResult] into the inner [Result].
+    #[inline]
+    pub fn into_inner(self) -> Result<T, E> {
+        self.0
+    }
+}
+
+impl<T, E> From<Result<T, E>> for SerdeResult<T, E> {
+    #[inline]
+    fn from(result: Result<T, E>) -> Self {
+        SerdeResult(result)
+    }
+}
+
+impl<T, E> From<SerdeResult<T, E>> for Result<T, E> {
+    #[inline]
+    fn from(result: SerdeResult<T, E>) -> Self {
+        result.0
+    }
+}
+
+impl<T, E> Serialize for SerdeResult<T, E>
+where
+    T: Serialize,
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T, E> Deserialize<'de> for SerdeResult<T, E>
+where
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Result::deserialize(deserializer).map(SerdeResult)
+    }
+}
+
+/// Deserialize a [SerdeResult] then map the value with async function if it is Ok.
+/// 
+/// The deserialization happens synchronously when this function is called.
+/// If it fails, the returned future resolves to the error of the `deserializer` immediately.
+/// Otherwise, the returned future resolves to `Ok` of the [Result] mapped with [ExtraResult::map_fut].
+/// This function is only available with `serde` feature.
+/// 
+/// # Example
+/// ```
+/// use extra_result::*;
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut de = serde_json::Deserializer::from_str(r#"{"Ok": 1}"#);
+/// let result = deserialize_and_map_fut::<_, u8, String, _, _>(&mut de, async |x| x + 1).await;
+/// assert_eq!(result.unwrap(), Ok(2));
+/// # }
+/// ```
+pub fn deserialize_and_map_fut<'de, D, T, E, U, F>(deserializer: D, f: F) -> impl Future<Output = Result<Result<U, E>, D::Error>>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+    F: AsyncFnOnce(T) -> U,
+{
+    let result = SerdeResult::<T, E>::deserialize(deserializer);
+    async move {
+        match result {
+            Ok(SerdeResult(r)) => Ok(r.map_fut(f).await),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde_round_trip() {
+        let ok = SerdeResult::<u8, u8>(Ok(1));
+        let err = SerdeResult::<u8, u8>(Err(2));
+        assert_eq!(serde_json::to_string(&ok).unwrap(), r#"{"Ok":1}"#);
+        assert_eq!(serde_json::to_string(&err).unwrap(), r#"{"Err":2}"#);
+        assert_eq!(serde_json::from_str::<SerdeResult<u8, u8>>(r#"{"Ok":1}"#).unwrap(), ok);
+        assert_eq!(serde_json::from_str::<SerdeResult<u8, u8>>(r#"{"Err":2}"#).unwrap(), err);
+    }
+    #[tokio::test]
+    async fn deserialize_and_map_on_ok() {
+        let mut de = serde_json::Deserializer::from_str(r#"{"Ok":1}"#);
+        assert_eq!(deserialize_and_map_fut::<_, u8, u8, _, _>(&mut de, async |x| x + 1).await.unwrap(), Ok(2u8));
+    }
+    #[tokio::test]
+    async fn deserialize_and_map_on_err() {
+        let mut de = serde_json::Deserializer::from_str(r#"{"Err":1}"#);
+        assert_eq!(
+            deserialize_and_map_fut::<_, u8, u8, _, _>(&mut de, async |_| panic!("This should never be called")).await.unwrap(), 
+            Err(1u8)
+        );
+    }
+    #[tokio::test]
+    async fn deserialize_and_map_invalid() {
+        let mut de = serde_json::Deserializer::from_str(r#"{"Unknown":1}"#);
+        assert!(deserialize_and_map_fut::<_, u8, u8, u8, _>(&mut de, async |x| x).await.is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::{cell::RefCell, fmt, pin::Pin, task::{Context, Poll, Waker}};
+
+/// A [Future] of [Result] that can be cloned and awaited by many consumers while the producing future run once.
+/// 
+/// Every clone share the same producing future. The first clone that is polled drive it, and once it resolved,
+/// the outcome is cached and every clone, including those created afterward, resolves to a clone of it.
+/// Hence both `T` and `E` must be [Clone] to await it.
+/// 
+/// It is single-threaded. It uses [Rc] and [RefCell] so it is neither [Send] nor [Sync],
+/// and all the clones must be awaited on the same thread. It doesn't need any runtime nor `std`.
+/// The producing future must not await a clone of the same [SharedResult], otherwise it panics.
+/// 
+/// When a clone that is waiting is dropped, the other waiting clones are woken up so one of them can keep driving
+/// the producing future.
+/// This type is only available with `alloc` feature.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SharedResult<Fut>
+where
+    Fut: Future,
+{
+    state: Rc<RefCell<State<Fut>>>,
+}
+
+enum State<Fut>
+where
+    Fut: Future,
+{
+    Pending { fut: Pin<Box<Fut>>, waiters: Vec<Waker> },
+    Done(Fut::Output),
+}
+
+impl<T, E, Fut> SharedResult<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    /// Wrap a [Future] of [Result] into a [SharedResult].
+    pub fn new(fut: Fut) -> Self {
+        SharedResult { state: Rc::new(RefCell::new(State::Pending { fut: Box::pin(fut), waiters: Vec::new() })) }
+    }
+    /// Return the cached outcome if the producing future already resolved.
+    pub fn peek(&self) -> Option<Result<T, E>>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        match &*self.state.borrow() {
+            State::Done(result) => Some(result.clone()),
+            State::Pending { .. } => None,
+        }
+    }
+    /// Return true if both of them are clones of the same [SharedResult].
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl<Fut> Clone for SharedResult<Fut>
+where
+    Fut: Future,
+{
+    fn clone(&self) -> Self {
+        SharedResult { state: self.state.clone() }
+    }
+}
+
+impl<T, E, Fut> Future for SharedResult<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+    T: Clone,
+    E: Clone,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        let result = match &mut *state {
+            State::Done(result) => return Poll::Ready(result.clone()),
+            State::Pending { fut, waiters } => {
+                if !waiters.iter().any(|w| w.will_wake(cx.waker())) {
+                    waiters.push(cx.waker().clone());
+                }
+                match fut.as_mut().poll(cx) {
+                    Poll::Ready(result) => result,
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        };
+        let State::Pending { waiters, .. } = core::mem::replace(&mut *state, State::Done(result.clone())) else {
+            unreachable!()
+        };
+        drop(state);
+        waiters.into_iter().filter(|w| !w.will_wake(cx.waker())).for_each(Waker::wake);
+        Poll::Ready(result)
+    }
+}
+
+impl<Fut> Drop for SharedResult<Fut>
+where
+    Fut: Future,
+{
+    fn drop(&mut self) {
+        // The dropped clone may be the one whose waker the producing future hold, so wake the others to take over.
+        let waiters = match self.state.try_borrow_mut().as_deref_mut() {
+            Ok(State::Pending { waiters, .. }) => core::mem::take(waiters),
+            _ => return,
+        };
+        waiters.into_iter().for_each(Waker::wake);
+    }
+}
+
+impl<Fut> fmt::Debug for SharedResult<Fut>
+where
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state.try_borrow().as_deref() {
+            Ok(State::Pending { .. }) => "Pending",
+            Ok(State::Done(_)) => "Completed",
+            Err(_) => "Polling",
+        };
+        f.debug_struct("SharedResult").field("state", &format_args!("{state}")).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use crate::time::{Sleeper, tests::YieldSleeper};
+
+    #[tokio::test]
+    async fn shared_run_once() {
+        let runs = Cell::new(0);
+        let shared = SharedResult::new(async {
+            runs.set(runs.get() + 1);
+            YieldSleeper.sleep(0).await;
+            Ok::<u8, u8>(1)
+        });
+        let other = shared.clone();
+        assert_eq!(shared.peek(), None);
+        assert_eq!(tokio::join!(shared.clone(), other), (Ok(1u8), Ok(1u8)));
+        assert_eq!(shared.peek(), Some(Ok(1u8)));
+        assert_eq!(shared.clone().await, Ok(1u8));
+        assert_eq!(runs.get(), 1);
+    }
+    #[tokio::test]
+    async fn shared_on_err() {
+        let shared = SharedResult::new(async { Err::<u8, u8>(1) });
+        assert_eq!(shared.clone().await, Err(1u8));
+        assert_eq!(shared.await, Err(1u8));
+    }
+    #[tokio::test]
+    async fn shared_driver_dropped() {
+        let shared = SharedResult::new(async {
+            YieldSleeper.sleep(0).await;
+            Ok::<u8, u8>(1)
+        });
+        let mut driver = Box::pin(shared.clone());
+        assert!(driver.as_mut().poll(&mut Context::from_waker(Waker::noop())).is_pending());
+        drop(driver);
+        assert_eq!(alloc::format!("{shared:?}"), "SharedResult { state: Pending }");
+        assert_eq!(shared.clone().await, Ok(1u8));
+        assert_eq!(alloc::format!("{shared:?}"), "SharedResult { state: Completed }");
+    }
+}
@@ -0,0 +1,200 @@
+/// An abstraction of an executor that can run a future somewhere else, e.g. on a blocking pool.
+/// 
+/// The crate doesn't depend on any runtime. Combinators such as [ExtraResult::map_fut_on](crate::ExtraResult::map_fut_on)
+/// take a [Spawn] to dispatch their async work through it.
+/// 
+/// The contract is that the returned future resolves to the output of `fut` once `fut` is completed.
+/// Where and how `fut` is polled is up to the implementation. The simplest implementation return `fut` itself
+/// which run it inline on the current task.
+pub trait Spawn {
+    /// Run the future and return a future that resolves to its output.
+    fn spawn<Fut>(&self, fut: Fut) -> impl Future<Output = Fut::Output>
+    where
+        Fut: Future;
+}
+
+impl<S> Spawn for &S
+where
+    S: Spawn + ?Sized,
+{
+    #[inline]
+    fn spawn<Fut>(&self, fut: Fut) -> impl Future<Output = Fut::Output>
+    where
+        Fut: Future,
+    {
+        (**self).spawn(fut)
+    }
+}
+
+/// An abstraction of an executor that take the ownership of a future and run it in the background, i.e. fire-and-forget.
+/// 
+/// Combinators such as [ExtraResult::inspect_spawned_fut](crate::ExtraResult::inspect_spawned_fut) take a [SpawnDetached]
+/// to run a side effect that the main chain doesn't await.
+/// 
+/// The contract is that `fut` is polled to completion by the implementation, e.g. on another task, even though
+/// nothing is returned to the caller. Unlike [Spawn], returning `fut` to the caller is not possible,
+/// so a side effect can't be silently dropped without being polled.
+/// `fut` is `'static` because it may outlive the caller.
+/// 
+/// # Example
+/// An adapter for `tokio` local task.
+/// ```
+/// use extra_result::SpawnDetached;
+/// 
+/// struct TokioLocal;
+/// 
+/// impl SpawnDetached for TokioLocal {
+///     fn spawn_detached<Fut>(&self, fut: Fut)
+///     where
+///         Fut: Future<Output = ()> + 'static,
+///     {
+///         tokio::task::spawn_local(fut);
+///     }
+/// }
+/// ```
+pub trait SpawnDetached {
+    /// Take the future and run it to completion in the background.
+    fn spawn_detached<Fut>(&self, fut: Fut)
+    where
+        Fut: Future<Output = ()> + 'static;
+}
+
+impl<S> SpawnDetached for &S
+where
+    S: SpawnDetached + ?Sized,
+{
+    #[inline]
+    fn spawn_detached<Fut>(&self, fut: Fut)
+    where
+        Fut: Future<Output = ()> + 'static,
+    {
+        (**self).spawn_detached(fut)
+    }
+}
+
+/// A logical priority of async work dispatched through a [PrioritizedSpawn].
+/// 
+/// The variants are ordered from the lowest to the highest priority so they can be compared,
+/// e.g. to pick a queue of a scheduler. The default is [Priority::Normal].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Background work that can wait, e.g. a cache refresh.
+    Low,
+    /// Regular work.
+    #[default]
+    Normal,
+    /// Latency-critical work that should run before the others.
+    High,
+}
+
+/// An abstraction of a scheduler that can run a future at a given [Priority].
+/// 
+/// The crate doesn't depend on any runtime. Combinators such as
+/// [ExtraResult::map_fut_with_priority](crate::ExtraResult::map_fut_with_priority) take a [PrioritizedSpawn]
+/// to dispatch their async work through it.
+/// 
+/// The contract is the same as [Spawn], the returned future resolves to the output of `fut` once `fut` is completed.
+/// The `prio` is a hint. How it affects the order of polling is up to the implementation and
+/// an implementation that ignores it is valid.
+/// 
+/// # Example
+/// A trivial scheduler that run everything inline in the order of submission, first in first out, and only log the priority.
+/// ```
+/// use extra_result::*;
+/// use std::sync::Mutex;
+/// 
+/// #[derive(Default)]
+/// struct Fifo(Mutex<Vec<Priority>>);
+/// 
+/// impl PrioritizedSpawn for Fifo {
+///     fn spawn_with_priority<Fut: Future>(&self, prio: Priority, fut: Fut) -> impl Future<Output = Fut::Output> {
+///         self.0.lock().unwrap().push(prio);
+///         fut
+///     }
+/// }
+/// 
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let fifo = Fifo::default();
+/// assert_eq!(Ok::<u8, u8>(1).map_fut_with_priority(&fifo, Priority::High, async |x| x + 1).await, Ok(2));
+/// assert_eq!(Ok::<u8, u8>(1).map_fut_with_priority(&fifo, Priority::Low, async |x| x - 1).await, Ok(0));
+/// assert_eq!(*fifo.0.lock().unwrap(), [Priority::High, Priority::Low]);
+/// # }
+/// ```
+pub trait PrioritizedSpawn {
+    /// Run the future at the given priority and return a future that resolves to its output.
+    fn spawn_with_priority<Fut>(&self, prio: Priority, fut: Fut) -> impl Future<Output = Fut::Output>
+    where
+        Fut: Future;
+}
+
+impl<S> PrioritizedSpawn for &S
+where
+    S: PrioritizedSpawn + ?Sized,
+{
+    #[inline]
+    fn spawn_with_priority<Fut>(&self, prio: Priority, fut: Fut) -> impl Future<Output = Fut::Output>
+    where
+        Fut: Future,
+    {
+        (**self).spawn_with_priority(prio, fut)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use core::{cell::Cell, pin::pin, task::{Context, Waker}};
+
+    /// A [Spawn] that run the future inline and count how many futures were spawned.
+    #[derive(Default)]
+    pub(crate) struct CountingSpawner {
+        pub(crate) spawned: Cell<usize>,
+    }
+
+    impl Spawn for CountingSpawner {
+        fn spawn<Fut>(&self, fut: Fut) -> impl Future<Output = Fut::Output>
+        where
+            Fut: Future,
+        {
+            self.spawned.set(self.spawned.get() + 1);
+            fut
+        }
+    }
+
+    impl PrioritizedSpawn for CountingSpawner {
+        fn spawn_with_priority<Fut>(&self, _: Priority, fut: Fut) -> impl Future<Output = Fut::Output>
+        where
+            Fut: Future,
+        {
+            self.spawn(fut)
+        }
+    }
+
+    /// A [SpawnDetached] that poll the future once immediately and drop it if it is not completed.
+    /// 
+    /// It counts how many futures were spawned and how many of them completed on that poll.
+    #[derive(Default)]
+    pub(crate) struct EagerSpawner {
+        pub(crate) spawned: Cell<usize>,
+        pub(crate) completed: Cell<usize>,
+    }
+
+    impl SpawnDetached for EagerSpawner {
+        fn spawn_detached<Fut>(&self, fut: Fut)
+        where
+            Fut: Future<Output = ()> + 'static,
+        {
+            self.spawned.set(self.spawned.get() + 1);
+            if pin!(fut).poll(&mut Context::from_waker(Waker::noop())).is_ready() {
+                self.completed.set(self.completed.get() + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn priority_order() {
+        assert!(Priority::Low < Priority::Normal && Priority::Normal < Priority::High);
+        assert_eq!(Priority::default(), Priority::Normal);
+    }
+}
@@ -0,0 +1,102 @@
+use futures::{Stream, StreamExt, stream};
+
+/// Add extra functionalities to a [Stream] of [Result].
+/// 
+/// This trait is only available with `futures` feature.
+pub trait ExtraResultStream<T, E>: Stream<Item = Result<T, E>> {
+    /// Same as [ExtraResult::map_fut](crate::ExtraResult::map_fut) but apply to every item of the [Stream].
+    /// 
+    /// It calls the async function with the value of each item that is Ok.
+    /// Items that are Err are passed through without calling the function.
+    /// The next item is pulled from the [Stream] only after the function of previous item is completed.
+    /// 
+    /// The [Stream] must be [Unpin]. Use [core::pin::pin] or `Box::pin` to pin a `!Unpin` stream first.
+    fn map_results<U, F>(self, f: F) -> impl Stream<Item = Result<U, E>>
+    where
+        Self: Sized + Unpin,
+        F: AsyncFnMut(T) -> U;
+}
+
+impl<S, T, E> ExtraResultStream<T, E> for S
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    #[inline]
+    fn map_results<U, F>(self, f: F) -> impl Stream<Item = Result<U, E>>
+    where
+        Self: Sized + Unpin,
+        F: AsyncFnMut(T) -> U,
+    {
+        stream::unfold((self, f), async |(mut s, mut f)| {
+            let item = match s.next().await? {
+                Ok(v) => Ok(f(v).await),
+                Err(e) => Err(e),
+            };
+            Some((item, (s, f)))
+        })
+    }
+}
+
+/// Poll all the given `Result` futures concurrently and yield their outputs in the order they complete.
+/// 
+/// Unlike a join that keep the order of the iterator, the earliest available result is yielded first
+/// regardless of its position in the iterator. Both Ok and Err are yielded, the stream ends once every future completed.
+/// 
+/// It is backed by [FuturesUnordered](futures::stream::FuturesUnordered) so every future is allocated on the heap
+/// and only the futures that are woken up are polled again. The memory is proportional to the number of pending futures.
+/// This function is only available with both `futures` and `alloc` features.
+#[cfg(feature = "alloc")]
+pub fn completion_stream<T, E, I>(futs: I) -> impl Stream<Item = Result<T, E>>
+where
+    I: IntoIterator,
+    I::Item: Future<Output = Result<T, E>>,
+{
+    futs.into_iter().collect::<stream::FuturesUnordered<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtraResult;
+    use core::pin::pin;
+
+    #[tokio::test]
+    async fn into_result_stream_on_ok() {
+        let mut s = pin!(Result::<u8, u8>::Ok(1).into_result_stream());
+        assert_eq!(s.next().await, Some(Ok(1u8)));
+        assert_eq!(s.next().await, None);
+    }
+    #[tokio::test]
+    async fn into_result_stream_on_err() {
+        let mut s = pin!(Result::<u8, u8>::Err(1).into_result_stream());
+        assert_eq!(s.next().await, Some(Err(1u8)));
+        assert_eq!(s.next().await, None);
+    }
+    #[tokio::test]
+    async fn map_results_mixed() {
+        let mut calls = 0;
+        {
+            let items = stream::iter([Ok(1u8), Err(2u8), Ok(3u8)]);
+            let mut s = pin!(items.map_results(async |x| { calls += 1; x + 1 }));
+            assert_eq!(s.next().await, Some(Ok(2u8)));
+            assert_eq!(s.next().await, Some(Err(2u8)));
+            assert_eq!(s.next().await, Some(Ok(4u8)));
+            assert_eq!(s.next().await, None);
+        }
+        assert_eq!(calls, 2);
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn completion_stream_in_completion_order() {
+        use crate::test_util::delayed;
+
+        let s = completion_stream([delayed(2, Ok(1)), delayed(0, Err(2)), delayed(1, Ok(3))]);
+        assert_eq!(s.collect::<alloc::vec::Vec<_>>().await, [Err(2u8), Ok(3), Ok(1)]);
+    }
+    #[cfg(feature = "alloc")]
+    #[tokio::test]
+    async fn completion_stream_empty() {
+        let mut s = completion_stream(core::iter::empty::<core::future::Ready<Result<u8, u8>>>());
+        assert_eq!(s.next().await, None);
+    }
+}
@@ -0,0 +1,29 @@
+use core::{marker::PhantomData, pin::Pin, task::{Context, Poll}};
+
+use crate::time::{Sleeper, tests::YieldSleeper};
+
+/// A future that panics if it is polled.
+/// 
+/// It is used to assert that a combinator never polls the future of the inactive arm.
+pub(crate) struct PanicOnPoll<T>(PhantomData<T>);
+
+impl<T> Future for PanicOnPoll<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+        panic!("This future should never be polled")
+    }
+}
+
+/// Create a [PanicOnPoll] future.
+pub(crate) fn never<T>() -> PanicOnPoll<T> {
+    PanicOnPoll(PhantomData)
+}
+
+/// Resolve to the given result after yielding the given number of times.
+pub(crate) async fn delayed<T, E>(yields: u8, result: Result<T, E>) -> Result<T, E> {
+    for _ in 0..yields {
+        YieldSleeper.sleep(0).await;
+    }
+    result
+}
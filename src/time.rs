@@ -0,0 +1,141 @@
+use crate::{Either, TimeoutOr, race::race};
+
+/// A source of delay for the time based combinators such as [timeout_fut] and [retry_fut](crate::retry_fut).
+/// 
+/// The crate doesn't depend on any runtime or time type. Instead, the user provide an implementation
+/// that return a future which completes after the given number of milliseconds.
+/// An implementation can be an adapter to the runtime timer or a timer wheel on bare metal.
+/// 
+/// # Example
+/// An adapter for `tokio`.
+/// ```
+/// use extra_result::Sleeper;
+/// use std::time::Duration;
+/// 
+/// struct TokioSleeper;
+/// 
+/// impl Sleeper for TokioSleeper {
+///     fn sleep(&self, ms: u64) -> impl Future<Output = ()> {
+///         tokio::time::sleep(Duration::from_millis(ms))
+///     }
+/// }
+/// ```
+pub trait Sleeper {
+    /// Return a future that completes after `ms` milliseconds.
+    fn sleep(&self, ms: u64) -> impl Future<Output = ()>;
+}
+
+impl<S> Sleeper for &S
+where
+    S: Sleeper + ?Sized,
+{
+    #[inline]
+    fn sleep(&self, ms: u64) -> impl Future<Output = ()> {
+        (**self).sleep(ms)
+    }
+}
+
+/// Await the [Result] future but give up if it doesn't complete within `ms` milliseconds.
+/// 
+/// It returns Ok with the value if the future resolved to Ok in time.
+/// It returns `Err(TimeoutOr::Inner(e))` if the future resolved to Err in time.
+/// If the sleep completes first, the future is dropped and it returns `Err(TimeoutOr::Elapsed)`.
+/// The future is polled before the sleep so if both are ready on the same poll, the future wins.
+pub fn timeout_fut<T, E, Fut, S>(sleeper: S, ms: u64, fut: Fut) -> impl Future<Output = Result<T, TimeoutOr<E>>>
+where
+    Fut: Future<Output = Result<T, E>>,
+    S: Sleeper,
+{
+    async move {
+        match race(fut, sleeper.sleep(ms)).await {
+            Either::Left(Ok(v)) => Ok(v),
+            Either::Left(Err(e)) => Err(TimeoutOr::Inner(e)),
+            Either::Right(()) => Err(TimeoutOr::Elapsed),
+        }
+    }
+}
+
+/// A [Sleeper] that spawns a thread for each sleep.
+/// 
+/// The thread sleeps for the given duration then wakes the task up so it works with any executor.
+/// It costs a thread per sleep so it is meant to be a fallback or an example of implementing [Sleeper].
+/// Prefer an adapter to the timer of the runtime in use.
+/// This type is only available with `std` feature.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadSleeper;
+
+#[cfg(feature = "std")]
+impl Sleeper for ThreadSleeper {
+    fn sleep(&self, ms: u64) -> impl Future<Output = ()> {
+        use core::{future::poll_fn, task::{Poll, Waker}, time::Duration};
+        use std::{sync::{Arc, Mutex}, thread};
+
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let mut spawned = false;
+        poll_fn(move |cx| {
+            let mut guard = state.lock().unwrap();
+            if guard.0 {
+                return Poll::Ready(());
+            }
+            guard.1 = Some(cx.waker().clone());
+            if !spawned {
+                spawned = true;
+                let state = state.clone();
+                thread::spawn(move || {
+                    thread::sleep(Duration::from_millis(ms));
+                    let mut guard = state.lock().unwrap();
+                    guard.0 = true;
+                    if let Some(waker) = guard.1.take() {
+                        waker.wake();
+                    }
+                });
+            }
+            Poll::Pending
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A [Sleeper] that never wakes up.
+    pub(crate) struct NeverSleeper;
+
+    impl Sleeper for NeverSleeper {
+        fn sleep(&self, _: u64) -> impl Future<Output = ()> {
+            core::future::pending()
+        }
+    }
+
+    /// A [Sleeper] that completes after yielding once, regardless of the duration.
+    pub(crate) struct YieldSleeper;
+
+    impl Sleeper for YieldSleeper {
+        fn sleep(&self, _: u64) -> impl Future<Output = ()> {
+            tokio::task::yield_now()
+        }
+    }
+
+    #[tokio::test]
+    async fn timeout_in_time() {
+        assert_eq!(timeout_fut(NeverSleeper, 10, async { Ok::<u8, u8>(1) }).await, Ok(1u8));
+        assert_eq!(timeout_fut(NeverSleeper, 10, async { Err::<u8, u8>(1) }).await, Err(TimeoutOr::Inner(1u8)));
+    }
+    #[tokio::test]
+    async fn timeout_elapsed() {
+        assert_eq!(
+            timeout_fut(YieldSleeper, 10, core::future::pending::<Result<u8, u8>>()).await, 
+            Err(TimeoutOr::Elapsed)
+        );
+    }
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn thread_sleeper_elapsed() {
+        assert_eq!(
+            timeout_fut(ThreadSleeper, 1, core::future::pending::<Result<u8, u8>>()).await, 
+            Err(TimeoutOr::Elapsed)
+        );
+    }
+}
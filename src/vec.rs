@@ -0,0 +1,189 @@
+use alloc::vec::Vec;
+
+use crate::{ExtraResult, Limiter, join::join_all};
+
+/// Add extra functionalities to a [Vec] of [Result].
+/// 
+/// This trait is only available with `alloc` feature.
+pub trait ExtraResultVec<T, E> {
+    /// Same as [ExtraResult::map_fut] but apply to every element of the [Vec] concurrently.
+    /// 
+    /// It calls the async function with the value of each element that is Ok and polls all of them concurrently.
+    /// Elements that are Err are passed through without calling the function.
+    /// The order of elements is preserved.
+    /// 
+    /// The function must be [AsyncFn] rather than [AsyncFnMut] because
+    /// a mutable borrow would prevent more than one call to be in progress at the same time.
+    fn map_all_fut<U, F>(self, f: F) -> impl Future<Output = Vec<Result<U, E>>>
+    where
+        F: AsyncFn(T) -> U;
+    /// Same as [ExtraResultVec::map_all_fut] but at most as many async functions as the `limiter` allow run at once.
+    /// 
+    /// It acquires a permit from the `limiter` before calling the async function with the value of each element that is Ok
+    /// and release it once the function completed. Elements that are Err are passed through without acquiring a permit.
+    /// The order of elements is preserved.
+    fn map_all_limited_fut<U, F, L>(self, limiter: &L, f: F) -> impl Future<Output = Vec<Result<U, E>>>
+    where
+        L: Limiter,
+        F: AsyncFn(T) -> U;
+    /// Same as [ExtraResultVec::map_all_fut] but the elements are processed in chunks of `chunk_size`.
+    /// 
+    /// The elements of each chunk are mapped concurrently and the chunks are processed one after another.
+    /// It bounds the number of async functions in progress to `chunk_size` without a [Limiter].
+    /// The last chunk may be smaller than `chunk_size`. If `chunk_size` is 0, it is treated as 1.
+    /// Elements that are Err are passed through without calling the function but they still take a slot in their chunk.
+    /// The order of elements is preserved.
+    fn map_chunked_fut<U, F>(self, chunk_size: usize, f: F) -> impl Future<Output = Vec<Result<U, E>>>
+    where
+        F: AsyncFn(T) -> U;
+}
+
+impl<T, E> ExtraResultVec<T, E> for Vec<Result<T, E>> {
+    #[inline]
+    fn map_all_fut<U, F>(self, f: F) -> impl Future<Output = Vec<Result<U, E>>>
+    where
+        F: AsyncFn(T) -> U,
+    {
+        async move {
+            let f = &f;
+            join_all(self.into_iter().map(|r| r.map_fut(f))).await
+        }
+    }
+    #[inline]
+    fn map_all_limited_fut<U, F, L>(self, limiter: &L, f: F) -> impl Future<Output = Vec<Result<U, E>>>
+    where
+        L: Limiter,
+        F: AsyncFn(T) -> U,
+    {
+        async move {
+            let f = &f;
+            join_all(self.into_iter().map(|r| r.map_fut(async |v| {
+                let _permit = limiter.acquire().await;
+                f(v).await
+            }))).await
+        }
+    }
+    #[inline]
+    fn map_chunked_fut<U, F>(self, chunk_size: usize, f: F) -> impl Future<Output = Vec<Result<U, E>>>
+    where
+        F: AsyncFn(T) -> U,
+    {
+        async move {
+            let f = &f;
+            let chunk_size = chunk_size.max(1);
+            let mut mapped = Vec::with_capacity(self.len());
+            let mut items = self.into_iter();
+            loop {
+                let chunk = join_all(items.by_ref().take(chunk_size).map(|r| r.map_fut(f))).await;
+                if chunk.is_empty() {
+                    break mapped;
+                }
+                mapped.extend(chunk);
+            }
+        }
+    }
+}
+
+/// Map every element of the [Vec] with async function that can fail and collect the values.
+/// 
+/// It calls the async function with each element, one at a time, in the order of the [Vec].
+/// If all of them return Ok, it returns Ok with all the values in the same order.
+/// If the function returns Err, it stops and returns the error. The remaining elements are dropped.
+/// This function is only available with `alloc` feature.
+pub fn traverse_fut<T, U, E, F>(items: Vec<T>, mut f: F) -> impl Future<Output = Result<Vec<U>, E>>
+where
+    F: AsyncFnMut(T) -> Result<U, E>,
+{
+    async move {
+        let mut values = Vec::with_capacity(items.len());
+        for item in items {
+            values.push(f(item).await?);
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    /// Track how many mappings are in progress at the same time.
+    #[derive(Default)]
+    struct Probe {
+        active: Cell<u8>,
+        max_active: Cell<u8>,
+    }
+
+    impl Probe {
+        /// Yield once while being counted as in progress then return `x`.
+        async fn track(&self, x: u8) -> u8 {
+            self.active.set(self.active.get() + 1);
+            self.max_active.set(self.max_active.get().max(self.active.get()));
+            tokio::task::yield_now().await;
+            self.active.set(self.active.get() - 1);
+            x
+        }
+    }
+
+    #[tokio::test]
+    async fn map_all_mixed() {
+        let res = Vec::from([Ok(1u8), Err(2u8), Ok(3u8)]);
+        assert_eq!(
+            res.map_all_fut(async |x| x + 1).await, 
+            [Ok(2u8), Err(2u8), Ok(4u8)]
+        );
+    }
+    #[tokio::test]
+    async fn map_all_concurrently() {
+        let probe = Probe::default();
+        let res = Vec::from([Ok::<u8, u8>(1), Ok(2), Ok(3)]);
+        let mapped = res.map_all_fut(async |x| probe.track(x).await).await;
+        assert_eq!(mapped, [Ok(1u8), Ok(2), Ok(3)]);
+        assert_eq!(probe.max_active.get(), 3);
+    }
+    #[tokio::test]
+    async fn map_chunked_partial_chunk() {
+        let probe = Probe::default();
+        let res = Vec::from([Ok::<u8, u8>(1), Err(2), Ok(3), Ok(4), Ok(5)]);
+        let mapped = res.map_chunked_fut(2, async |x| probe.track(x).await + 1).await;
+        assert_eq!(mapped, [Ok(2u8), Err(2), Ok(4), Ok(5), Ok(6)]);
+        assert_eq!(probe.max_active.get(), 2);
+    }
+    #[tokio::test]
+    async fn map_chunked_zero_size() {
+        let probe = Probe::default();
+        let res = Vec::from([Ok::<u8, u8>(1), Ok(2), Ok(3)]);
+        let mapped = res.map_chunked_fut(0, async |x| probe.track(x).await).await;
+        assert_eq!(mapped, [Ok(1u8), Ok(2), Ok(3)]);
+        assert_eq!(probe.max_active.get(), 1);
+        assert!(Vec::<Result<u8, u8>>::new().map_chunked_fut(2, async |x| x).await.is_empty());
+    }
+    #[tokio::test]
+    async fn traverse_all_ok() {
+        assert_eq!(
+            traverse_fut(Vec::from([1u8, 2, 3]), async |x| Ok::<u8, u8>(x + 1)).await, 
+            Ok(Vec::from([2u8, 3, 4]))
+        );
+    }
+    #[tokio::test]
+    async fn traverse_stop_at_err() {
+        let mut calls = 0;
+        assert_eq!(
+            traverse_fut(Vec::from([1u8, 2, 3]), async |x| { calls += 1; if x == 2 { Err(x) } else { Ok(x) } }).await, 
+            Err(2u8)
+        );
+        assert_eq!(calls, 2);
+    }
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn map_all_limited_concurrency() {
+        let limiter = crate::CountingLimiter::new(2);
+        let probe = Probe::default();
+        let res = Vec::from([Ok::<u8, u8>(1), Err(2), Ok(3), Ok(4), Ok(5)]);
+        let mapped = res.map_all_limited_fut(&limiter, async |x| probe.track(x).await + 1).await;
+        assert_eq!(mapped, [Ok(2u8), Err(2), Ok(4), Ok(5), Ok(6)]);
+        assert_eq!(probe.max_active.get(), 2);
+        assert_eq!(limiter.available(), 2);
+    }
+}